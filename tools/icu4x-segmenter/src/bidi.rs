@@ -0,0 +1,101 @@
+//! Text direction: HTML `dir=auto` detection and full UAX #9 level runs.
+
+use icu_properties::bidi::BidiClassAdapter;
+use icu_properties::{maps, BidiClass};
+use unicode_bidi::{BidiInfo, Level};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn base_direction(text: &str) -> String {
+    // HTML dir=auto: the first strong character (L, R or AL) decides; digits and punctuation are skipped
+    let bidi = maps::bidi_class();
+    for c in text.chars() {
+        match bidi.get(c) {
+            BidiClass::LeftToRight => return "ltr".to_string(),
+            BidiClass::RightToLeft | BidiClass::ArabicLetter => return "rtl".to_string(),
+            _ => {}
+        }
+    }
+    "neutral".to_string()
+}
+
+/// A run of text at one resolved embedding level, as UTF-8 byte offsets
+/// `[start, end)`. Even levels are left-to-right, odd levels right-to-left.
+#[wasm_bindgen]
+pub struct BidiRun {
+    pub start: u32,
+    pub end: u32,
+    pub level: u8,
+}
+
+/// Directional runs in logical order, resolved with the Unicode Bidirectional
+/// Algorithm (UAX #9) over ICU bidi class data.
+///
+/// `base` is "ltr", "rtl" or "auto"; "auto" (and anything unrecognised) picks
+/// each paragraph's level from its first strong character, falling back to
+/// LTR. Neutrals and European/Arabic numbers are resolved per the algorithm,
+/// so "عدد 123" yields the Arabic word and space at level 1 followed by the
+/// digits at level 2. Wrapping each run with `dir` set from its level's parity
+/// reproduces the intended display.
+#[wasm_bindgen]
+pub fn bidi_runs(text: &str, base: &str) -> Vec<BidiRun> {
+    let base_level = match base.trim().to_ascii_lowercase().as_str() {
+        "ltr" => Some(Level::ltr()),
+        "rtl" => Some(Level::rtl()),
+        _ => None,
+    };
+    let adapter = BidiClassAdapter::new(maps::bidi_class());
+    let info = BidiInfo::new_with_data_source(&adapter, text, base_level);
+    // `levels` has one entry per byte; every byte of a scalar shares its level
+    let mut out = Vec::new();
+    let mut start = 0;
+    let mut current: Option<u8> = None;
+    for (i, _) in text.char_indices() {
+        let level = info.levels[i].number();
+        if current != Some(level) {
+            if let Some(prev) = current {
+                out.push(BidiRun {
+                    start: start as u32,
+                    end: i as u32,
+                    level: prev,
+                });
+            }
+            start = i;
+            current = Some(level);
+        }
+    }
+    if let Some(level) = current {
+        out.push(BidiRun {
+            start: start as u32,
+            end: text.len() as u32,
+            level,
+        });
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levels(text: &str, base: &str) -> Vec<(u32, u32, u8)> {
+        let runs = bidi_runs(text, base);
+        runs.into_iter()
+            .map(|r| (r.start, r.end, r.level))
+            .collect()
+    }
+
+    #[test]
+    fn bidi_runs_resolve_levels_per_base() {
+        // Arabic word and space at 1, European digits raised to 2
+        assert_eq!(levels("عدد 123", "auto"), [(0, 7, 1), (7, 10, 2)]);
+        // The space between Hebrew and digits resolves to the RTL run
+        assert_eq!(
+            levels("abc שלום 12 end", "ltr"),
+            [(0, 4, 0), (4, 13, 1), (13, 15, 2), (15, 19, 0)]
+        );
+        // Latin inside an RTL paragraph is one run at level 2
+        assert_eq!(levels("abc def", "rtl"), [(0, 7, 2)]);
+        assert!(levels("", "auto").is_empty());
+    }
+}
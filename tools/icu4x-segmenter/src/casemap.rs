@@ -0,0 +1,47 @@
+//! Case folding and word-wise titlecasing.
+
+use icu_casemap::CaseMapper;
+#[cfg(feature = "word")]
+use icu_casemap::TitlecaseMapper;
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "word")]
+use crate::parse_locale;
+#[cfg(feature = "word")]
+use crate::word::word_segments;
+
+/// Unicode default full case folding, for caseless matching rather than display.
+///
+/// Unlike `to_lowercase`, folding maps "ß" and "ẞ" to "ss", ligatures such as
+/// "ﬁ" to "fi", and every sigma form to "σ", so "STRASSE" and "straße" fold to
+/// the same string. Folding is locale-independent: "İ" folds to "i\u{307}"
+/// and "I" to "i"; use `case_fold_turkic` to make "İ"/"i" and "I"/"ı" match.
+#[wasm_bindgen]
+pub fn case_fold(text: &str) -> String {
+    CaseMapper::new().fold_string(text)
+}
+
+#[wasm_bindgen]
+pub fn case_fold_turkic(text: &str) -> String {
+    // Turkic tailoring of full folding: "İ" -> "i" and "I" -> "ı"
+    CaseMapper::new().fold_turkic_string(text)
+}
+
+#[cfg(feature = "word")]
+#[wasm_bindgen]
+pub fn titlecase(text: &str, locale: &str) -> String {
+    // Each word-like segment is titlecased on its own (first cased letter upper, rest lower);
+    // empty or unparseable locales use root rules
+    let langid = parse_locale(locale).id;
+    let mapper = TitlecaseMapper::new();
+    let mut out = String::with_capacity(text.len());
+    for (start, end, word_like) in word_segments(text) {
+        let segment = &text[start..end];
+        if word_like {
+            out.push_str(&mapper.titlecase_segment_to_string(segment, &langid, Default::default()));
+        } else {
+            out.push_str(segment);
+        }
+    }
+    out
+}
@@ -0,0 +1,189 @@
+//! Splitting text into budget-sized chunks at the coarsest boundary that fits.
+
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "line")]
+use crate::data::line_segmenter;
+use crate::data::{grapheme_segmenter, sentence_segmenter, word_segmenter};
+
+fn is_clean_cut(text: &str, at: usize) -> bool {
+    // Text after the cut starts a word or follows a space, or nothing is left
+    let before_space = text[..at].ends_with(char::is_whitespace);
+    text[at..]
+        .chars()
+        .next()
+        .is_none_or(|c| c.is_whitespace() || c.is_alphanumeric() || before_space)
+}
+
+fn furthest_boundary(levels: &[Vec<usize>], start: usize, limit: usize) -> Option<usize> {
+    // Furthest boundary in (start, limit] from the first level that has one
+    levels.iter().find_map(|breaks| {
+        let i = breaks.partition_point(|&b| b <= limit);
+        breaks[..i].last().copied().filter(|&b| b > start)
+    })
+}
+
+/// Cut points (including 0 and `text.len()`) such that every chunk between two
+/// consecutive offsets is at most `max_bytes` long.
+///
+/// Each chunk ends at the furthest sentence boundary that fits; if no sentence
+/// boundary fits, the furthest line break opportunity, then word boundary, then
+/// grapheme boundary is used. Text with none of those falls back to scalar
+/// boundaries, so a chunk only exceeds the budget when a single scalar value is
+/// longer than `max_bytes`.
+#[cfg(feature = "line")]
+#[wasm_bindgen]
+pub fn split_for_budget(text: &str, max_bytes: u32) -> Vec<u32> {
+    let max = max_bytes as usize;
+    let levels: [Vec<usize>; 4] = [
+        sentence_segmenter().segment_str(text).collect(),
+        line_segmenter().segment_str(text).collect(),
+        word_segmenter().segment_str(text).collect(),
+        grapheme_segmenter().segment_str(text).collect(),
+    ];
+    let mut out = vec![0];
+    let mut start = 0;
+    while start < text.len() {
+        let limit = start.saturating_add(max).min(text.len());
+        let end = furthest_boundary(&levels, start, limit).unwrap_or_else(|| {
+            let fit = (start + 1..=limit)
+                .rev()
+                .find(|&b| text.is_char_boundary(b));
+            fit.unwrap_or_else(|| {
+                // A single scalar longer than the budget is emitted whole
+                let c = text[start..].chars().next().map_or(1, char::len_utf8);
+                start + c
+            })
+        });
+        out.push(end as u32);
+        start = end;
+    }
+    out
+}
+
+/// Translation-ready chunks of at most `max_chars` grapheme clusters each.
+///
+/// Chunks end at the furthest sentence boundary that fits, falling back to the
+/// furthest word boundary and, for a single word longer than the budget, to a
+/// grapheme boundary. Word cuts that would leave punctuation at the start of
+/// the next chunk are avoided where another fits. Whitespace after a cut stays
+/// at the end of the preceding chunk and does not count against `max_chars`.
+/// Every chunk is non-empty, and concatenating the chunks reproduces `text`
+/// exactly. A `max_chars` of 0 is treated as 1.
+#[wasm_bindgen]
+pub fn chunk_text(text: &str, max_chars: u32) -> Vec<String> {
+    let max = (max_chars as usize).max(1);
+    let graphemes: Vec<usize> = grapheme_segmenter().segment_str(text).collect();
+    let levels: [Vec<usize>; 2] = [
+        sentence_segmenter().segment_str(text).collect(),
+        word_segmenter().segment_str(text).collect(),
+    ];
+    let mut out = Vec::new();
+    let mut start = 0;
+    let mut start_cluster = 0;
+    while start < text.len() {
+        let limit = graphemes[(start_cluster + max).min(graphemes.len() - 1)];
+        // Prefer a cut that doesn't strand trailing punctuation (the "." after a
+        // word); word and sentence boundaries are always grapheme boundaries
+        let cut = levels
+            .iter()
+            .find_map(|breaks| {
+                let i = breaks.partition_point(|&b| b <= limit);
+                breaks[..i]
+                    .iter()
+                    .rev()
+                    .take_while(|&&b| b > start)
+                    .find(|&&b| is_clean_cut(text, b))
+                    .copied()
+            })
+            .or_else(|| furthest_boundary(&levels, start, limit))
+            .unwrap_or(limit);
+        // Whitespace after the cut goes with this chunk, outside the budget
+        let end = text[cut..]
+            .find(|c: char| !c.is_whitespace())
+            .map_or(text.len(), |n| cut + n);
+        out.push(text[start..end].to_string());
+        start = end;
+        start_cluster = graphemes.partition_point(|&b| b < end);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grapheme::count_graphemes;
+
+    const FAMILY: &str = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+
+    #[test]
+    fn chunk_text_reconstructs_within_budget() {
+        let text = format!(
+            "First sentence here. Second one is a bit longer! Ein Satz. 日本語の文です。{FAMILY}{FAMILY}{FAMILY} supercalifragilistic"
+        );
+        for max in [0, 1, 5, 12, 30, 1000] {
+            let chunks = chunk_text(&text, max);
+            assert_eq!(chunks.concat(), text, "max {max}");
+            for chunk in &chunks {
+                assert!(!chunk.is_empty());
+                assert!(
+                    count_graphemes(chunk.trim_end()) <= max.max(1),
+                    "{chunk:?} over {max}"
+                );
+            }
+        }
+        assert_eq!(
+            chunk_text("One two. Three four.", 10),
+            ["One two. ", "Three ", "four."]
+        );
+        // Whitespace after a cut rides along with the chunk before it
+        assert_eq!(chunk_text("Three four five.", 10), ["Three four ", "five."]);
+        assert_eq!(chunk_text("abcde fg", 5), ["abcde ", "fg"]);
+        assert_eq!(
+            chunk_text("Hi there. Bye now.", 9),
+            ["Hi there. ", "Bye now."]
+        );
+        assert_eq!(
+            chunk_text("Hello world.   Second sentence here.", 14),
+            ["Hello world.   ", "Second ", "sentence here."]
+        );
+        assert!(chunk_text("", 10).is_empty());
+    }
+
+    #[cfg(feature = "line")]
+    fn assert_budget(text: &str, max: u32) {
+        let cuts = split_for_budget(text, max);
+        assert_eq!(cuts.first(), Some(&0));
+        assert_eq!(cuts.last().copied(), Some(text.len() as u32));
+        for pair in cuts.windows(2) {
+            let (start, end) = (pair[0] as usize, pair[1] as usize);
+            assert!(start < end && text.is_char_boundary(end), "{cuts:?}");
+            // Only a single scalar wider than the budget may overflow it
+            let scalar_overflow = text[start..end].chars().count() == 1;
+            assert!(
+                end - start <= max as usize || scalar_overflow,
+                "{cuts:?} over {max}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "line")]
+    fn split_for_budget_never_exceeds_max_bytes() {
+        let text =
+            format!("First sentence here. Second one! 日本語の文です。{FAMILY} über-long-compound");
+        for max in [1, 2, 3, 7, 16, 40, 1000] {
+            assert_budget(&text, max);
+        }
+        // No sentence, line or word boundary inside: grapheme fallback
+        let run = "x".repeat(50);
+        assert_eq!(split_for_budget(&run, 20), [0, 20, 40, 50]);
+        // One cluster of 41 bytes: scalar fallback
+        let stacked = format!("e{}", "\u{301}".repeat(20));
+        assert_budget(&stacked, 5);
+        // Budget smaller than a scalar: each scalar is emitted whole
+        assert_eq!(split_for_budget("日本", 2), [0, 3, 6]);
+        assert_eq!(split_for_budget("ab", 0), [0, 1, 2]);
+        assert_eq!(split_for_budget("", 10), [0]);
+    }
+}
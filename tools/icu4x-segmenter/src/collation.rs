@@ -0,0 +1,55 @@
+//! Locale-aware string comparison and sorting.
+
+use icu_collator::{Collator, CollatorOptions};
+use wasm_bindgen::prelude::*;
+
+use crate::parse_locale;
+
+fn collator_for(locale: &str) -> Option<Collator> {
+    // Locales without tailoring fall back to root collation through the compiled data
+    let data_locale = (&parse_locale(locale)).into();
+    Collator::try_new(&data_locale, CollatorOptions::new()).ok()
+}
+
+#[wasm_bindgen]
+pub fn collate_compare(a: &str, b: &str, locale: &str) -> i32 {
+    // -1, 0 or 1; e.g. "ä" sorts after "z" for "sv" but next to "a" for "de"
+    let ordering = match collator_for(locale) {
+        Some(collator) => collator.compare(a, b),
+        None => a.cmp(b),
+    };
+    ordering as i32
+}
+
+#[wasm_bindgen]
+pub fn sort_strings(items: Vec<String>, locale: &str) -> Vec<String> {
+    let mut items = items;
+    match collator_for(locale) {
+        Some(collator) => items.sort_by(|a, b| collator.compare(a, b)),
+        None => items.sort(),
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collation_follows_locale_tailoring() {
+        assert_eq!(collate_compare("ä", "z", "sv"), 1);
+        assert_eq!(collate_compare("ä", "z", "de"), -1);
+        assert_eq!(collate_compare("a", "a", "en"), 0);
+        let items = ["zebra", "Äpfel", "apple", "ängel"]
+            .map(String::from)
+            .to_vec();
+        assert_eq!(
+            sort_strings(items.clone(), "de"),
+            ["ängel", "Äpfel", "apple", "zebra"]
+        );
+        assert_eq!(
+            sort_strings(items, "sv"),
+            ["apple", "zebra", "ängel", "Äpfel"]
+        );
+    }
+}
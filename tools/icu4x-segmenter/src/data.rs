@@ -0,0 +1,228 @@
+//! Construction of the segmenters, from baked or runtime-loaded data.
+//!
+//! With `compiled_data` (the default) every segmenter uses data compiled into
+//! the binary. `buffer_provider` adds `init_data`, which installs an ICU4X
+//! postcard blob (`icu4x-datagen --format blob`) that takes precedence over
+//! the baked data; without `compiled_data` the blob is the only source, so a
+//! build can ship just the locales and models a user enabled. `init_data`
+//! rejects a blob that cannot build every enabled segmenter, so once it has
+//! succeeded no segmenter falls back or fails.
+//!
+//! Each constructor exists only when its segmenter's feature is enabled. Only
+//! segmentation data is pluggable; the auxiliary components (properties, case
+//! mapping, normalization, collation, formatting) always use compiled data.
+
+#[cfg(feature = "grapheme")]
+use icu_segmenter::GraphemeClusterSegmenter;
+#[cfg(feature = "sentence")]
+use icu_segmenter::SentenceSegmenter;
+#[cfg(feature = "word")]
+use icu_segmenter::WordSegmenter;
+#[cfg(feature = "line")]
+use icu_segmenter::{LineBreakOptions, LineSegmenter};
+
+#[cfg(not(any(feature = "compiled_data", feature = "buffer_provider")))]
+compile_error!("enable `compiled_data`, `buffer_provider`, or both");
+
+#[cfg(feature = "buffer_provider")]
+mod blob {
+    use std::cell::RefCell;
+
+    use icu_provider_blob::BlobDataProvider;
+    use wasm_bindgen::prelude::*;
+
+    thread_local! {
+        static PROVIDER: RefCell<Option<BlobDataProvider>> = const { RefCell::new(None) };
+    }
+
+    /// Installs a postcard data blob used by every segmenter built afterwards.
+    /// Segmenters that already exist (e.g. a live `Segmenter` handle) keep
+    /// their data.
+    ///
+    /// Fails if the bytes are not a valid ICU4X blob, or if the blob cannot
+    /// build one of the segmenters compiled into this build (a trimmed blob
+    /// without the LSTM models or dictionaries that the `auto` line and word
+    /// segmenters need, say). The error names the missing segmenter, and the
+    /// previously installed data stays in place.
+    #[wasm_bindgen]
+    pub fn init_data(bytes: &[u8]) -> Result<(), JsError> {
+        install(bytes).map_err(|err| JsError::new(&err))
+    }
+
+    pub(crate) fn install(bytes: &[u8]) -> Result<(), String> {
+        let provider =
+            BlobDataProvider::try_new_from_blob(bytes.into()).map_err(|err| err.to_string())?;
+        check_segmenters(&provider)?;
+        PROVIDER.with(|slot| *slot.borrow_mut() = Some(provider));
+        Ok(())
+    }
+
+    fn check_segmenters(provider: &BlobDataProvider) -> Result<(), String> {
+        // Building each enabled segmenter once up front turns a missing key into an
+        // init_data error instead of a failure on first use
+        let _ = provider;
+        #[cfg(feature = "line")]
+        icu_segmenter::LineSegmenter::try_new_auto_with_buffer_provider(provider)
+            .map_err(|err| format!("line segmenter: {err}"))?;
+        #[cfg(feature = "word")]
+        {
+            icu_segmenter::WordSegmenter::try_new_auto_with_buffer_provider(provider)
+                .map_err(|err| format!("word segmenter: {err}"))?;
+            icu_segmenter::WordSegmenter::try_new_dictionary_with_buffer_provider(provider)
+                .map_err(|err| format!("dictionary word segmenter: {err}"))?;
+        }
+        #[cfg(feature = "sentence")]
+        icu_segmenter::SentenceSegmenter::try_new_with_buffer_provider(provider)
+            .map_err(|err| format!("sentence segmenter: {err}"))?;
+        #[cfg(feature = "grapheme")]
+        icu_segmenter::GraphemeClusterSegmenter::try_new_with_buffer_provider(provider)
+            .map_err(|err| format!("grapheme segmenter: {err}"))?;
+        Ok(())
+    }
+
+    #[cfg(any(
+        feature = "line",
+        feature = "word",
+        feature = "sentence",
+        feature = "grapheme"
+    ))]
+    pub(crate) fn load<T>(
+        build: impl FnOnce(&BlobDataProvider) -> Result<T, icu_segmenter::SegmenterError>,
+    ) -> Option<T> {
+        // None when no blob is installed or the blob lacks the requested data
+        PROVIDER.with(|slot| slot.borrow().as_ref().and_then(|p| build(p).ok()))
+    }
+}
+
+#[cfg(feature = "buffer_provider")]
+pub use blob::init_data;
+
+#[cfg(all(
+    not(feature = "compiled_data"),
+    any(
+        feature = "line",
+        feature = "word",
+        feature = "sentence",
+        feature = "grapheme"
+    )
+))]
+fn missing_data() -> ! {
+    // Thrown as a JS Error carrying the message rather than a bare wasm trap
+    wasm_bindgen::throw_str("no segmentation data: call init_data() with an ICU4X blob first")
+}
+
+#[cfg(feature = "line")]
+pub(crate) fn line_segmenter() -> LineSegmenter {
+    line_segmenter_with_options(LineBreakOptions::default())
+}
+
+#[cfg(feature = "line")]
+pub(crate) fn line_segmenter_with_options(options: LineBreakOptions) -> LineSegmenter {
+    #[cfg(feature = "buffer_provider")]
+    if let Some(seg) =
+        blob::load(|p| LineSegmenter::try_new_auto_with_options_with_buffer_provider(p, options))
+    {
+        return seg;
+    }
+    #[cfg(feature = "compiled_data")]
+    {
+        LineSegmenter::new_auto_with_options(options)
+    }
+    #[cfg(not(feature = "compiled_data"))]
+    {
+        missing_data()
+    }
+}
+
+#[cfg(feature = "word")]
+pub(crate) fn word_segmenter() -> WordSegmenter {
+    #[cfg(feature = "buffer_provider")]
+    if let Some(seg) = blob::load(WordSegmenter::try_new_auto_with_buffer_provider) {
+        return seg;
+    }
+    #[cfg(feature = "compiled_data")]
+    {
+        WordSegmenter::new_auto()
+    }
+    #[cfg(not(feature = "compiled_data"))]
+    {
+        missing_data()
+    }
+}
+
+#[cfg(feature = "word")]
+pub(crate) fn dictionary_word_segmenter() -> WordSegmenter {
+    #[cfg(feature = "buffer_provider")]
+    if let Some(seg) = blob::load(WordSegmenter::try_new_dictionary_with_buffer_provider) {
+        return seg;
+    }
+    #[cfg(feature = "compiled_data")]
+    {
+        WordSegmenter::new_dictionary()
+    }
+    #[cfg(not(feature = "compiled_data"))]
+    {
+        missing_data()
+    }
+}
+
+#[cfg(feature = "sentence")]
+pub(crate) fn sentence_segmenter() -> SentenceSegmenter {
+    #[cfg(feature = "buffer_provider")]
+    if let Some(seg) = blob::load(SentenceSegmenter::try_new_with_buffer_provider) {
+        return seg;
+    }
+    #[cfg(feature = "compiled_data")]
+    {
+        SentenceSegmenter::new()
+    }
+    #[cfg(not(feature = "compiled_data"))]
+    {
+        missing_data()
+    }
+}
+
+#[cfg(feature = "grapheme")]
+pub(crate) fn grapheme_segmenter() -> GraphemeClusterSegmenter {
+    #[cfg(feature = "buffer_provider")]
+    if let Some(seg) = blob::load(GraphemeClusterSegmenter::try_new_with_buffer_provider) {
+        return seg;
+    }
+    #[cfg(feature = "compiled_data")]
+    {
+        GraphemeClusterSegmenter::new()
+    }
+    #[cfg(not(feature = "compiled_data"))]
+    {
+        missing_data()
+    }
+}
+
+#[cfg(all(test, feature = "buffer_provider"))]
+mod tests {
+    use super::blob::install;
+
+    #[test]
+    fn invalid_blobs_are_rejected() {
+        assert!(install(&[]).is_err());
+        assert!(install(b"not a postcard blob").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "line")]
+    fn blobs_missing_segmenter_data_are_rejected() {
+        use icu_provider::datagen::DataExporter;
+        use icu_provider::hello_world::HelloWorldV1Marker;
+        use icu_provider::KeyedDataMarker;
+        use icu_provider_blob::export::BlobExporter;
+
+        // A well-formed blob holding only an unrelated, empty key
+        let mut bytes = Vec::new();
+        let mut exporter = BlobExporter::new_v2_with_sink(Box::new(&mut bytes));
+        exporter.flush(HelloWorldV1Marker::KEY).unwrap();
+        exporter.close().unwrap();
+        drop(exporter);
+        let err = install(&bytes).unwrap_err();
+        assert!(err.starts_with("line segmenter"), "{err}");
+    }
+}
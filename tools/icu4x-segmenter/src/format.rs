@@ -0,0 +1,97 @@
+//! Locale-aware list and number formatting.
+
+use fixed_decimal::{FixedDecimal, FloatPrecision};
+use icu_decimal::FixedDecimalFormatter;
+use icu_list::{ListFormatter, ListLength};
+use wasm_bindgen::prelude::*;
+
+use crate::parse_locale;
+
+#[wasm_bindgen]
+pub fn format_list(items: Vec<String>, locale: &str, list_type: &str) -> String {
+    // list_type is "and", "or" or "unit"; unknown values format as "and"
+    if items.len() < 2 {
+        return items.into_iter().next().unwrap_or_default();
+    }
+    let data_locale = (&parse_locale(locale)).into();
+    let formatter = match list_type.trim().to_ascii_lowercase().as_str() {
+        "or" => ListFormatter::try_new_or_with_length(&data_locale, ListLength::Wide),
+        "unit" => ListFormatter::try_new_unit_with_length(&data_locale, ListLength::Wide),
+        _ => ListFormatter::try_new_and_with_length(&data_locale, ListLength::Wide),
+    };
+    match formatter {
+        Ok(formatter) => formatter.format_to_string(items.iter()),
+        Err(_) => items.join(", "),
+    }
+}
+
+fn format_decimal(value: FixedDecimal, locale: &str) -> String {
+    match FixedDecimalFormatter::try_new(&(&parse_locale(locale)).into(), Default::default()) {
+        Ok(formatter) => formatter.format_to_string(&value),
+        Err(_) => value.to_string(),
+    }
+}
+
+#[wasm_bindgen]
+pub fn format_number(value: f64, locale: &str) -> String {
+    // Shortest round-tripping digits; NaN and infinities have no FixedDecimal form
+    match FixedDecimal::try_from_f64(value, FloatPrecision::Floating) {
+        Ok(decimal) => format_decimal(decimal, locale),
+        Err(_) => value.to_string(),
+    }
+}
+
+/// Formats a ratio (0.425 for 42.5%) as a percentage with the locale's
+/// grouping and decimal mark, e.g. "42,5%" or "1.234%" in German.
+///
+/// The percentage is rounded half away from zero to at most
+/// `max_fraction_digits` digits (capped at 15) and trailing zeros are
+/// dropped, so 2 digits give "42.5%" for 0.425 and "42%" for 0.42. ICU4X 1.5
+/// ships no percent patterns, so the sign is always appended directly after
+/// the digits.
+#[wasm_bindgen]
+pub fn format_percent(ratio: f64, locale: &str, max_fraction_digits: u8) -> String {
+    match FixedDecimal::try_from_f64(ratio * 100.0, FloatPrecision::Floating) {
+        Ok(mut decimal) => {
+            decimal.half_expand(-i16::from(max_fraction_digits.min(15)));
+            decimal.trim_end();
+            format!("{}%", format_decimal(decimal, locale))
+        }
+        Err(_) => format!("{}%", ratio * 100.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_use_locale_conjunctions() {
+        let items = ["a", "b", "c"].map(String::from).to_vec();
+        assert_eq!(format_list(items.clone(), "fr", "and"), "a, b et c");
+        assert_eq!(format_list(items.clone(), "en", "or"), "a, b, or c");
+        assert_eq!(format_list(items, "en", "bogus"), "a, b, and c");
+        assert_eq!(format_list(vec![], "en", "and"), "");
+        assert_eq!(format_list(vec!["solo".to_string()], "de", "or"), "solo");
+    }
+
+    #[test]
+    fn numbers_follow_locale_separators() {
+        assert_eq!(format_number(1234.56, "de"), "1.234,56");
+        assert_eq!(format_number(1234.56, "en"), "1,234.56");
+        assert_eq!(format_number(1234567.0, "hi"), "12,34,567");
+        // Invalid tags fall back to root
+        assert_eq!(format_number(1234.5, "!!"), "1,234.5");
+    }
+
+    #[test]
+    fn percent_keeps_requested_fraction_digits() {
+        assert_eq!(format_percent(0.425, "en", 1), "42.5%");
+        assert_eq!(format_percent(0.425, "de", 1), "42,5%");
+        assert_eq!(format_percent(0.425, "en", 0), "43%");
+        assert_eq!(format_percent(0.42, "en", 2), "42%");
+        assert_eq!(format_percent(0.12345, "en", 2), "12.35%");
+        assert_eq!(format_percent(12.34, "de", 0), "1.234%");
+        assert_eq!(format_percent(1.0, "en", 1), "100%");
+    }
+}
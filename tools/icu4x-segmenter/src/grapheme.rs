@@ -0,0 +1,183 @@
+//! Extended grapheme clusters: counting, truncation, cursor movement and
+//! cluster-safe diffing.
+
+use wasm_bindgen::prelude::*;
+
+use crate::data::grapheme_segmenter;
+
+#[wasm_bindgen]
+pub fn grapheme_break_points(text: &str) -> Vec<u32> {
+    // Extended grapheme clusters: ZWJ emoji sequences and combining marks stay in one cluster
+    let seg = grapheme_segmenter();
+    let mut out = Vec::new();
+    for idx in seg.segment_str(text) {
+        out.push(idx as u32);
+    }
+    out
+}
+
+#[wasm_bindgen]
+pub fn count_graphemes(text: &str) -> u32 {
+    // Boundaries include both ends, so clusters = boundaries - 1 (and 0 for empty input)
+    let seg = grapheme_segmenter();
+    seg.segment_str(text).count().saturating_sub(1) as u32
+}
+
+/// Cuts `text` after at most `max` grapheme clusters and appends `ellipsis` when
+/// anything was removed. The ellipsis is not counted against `max`; text that
+/// already fits is returned unchanged.
+#[wasm_bindgen]
+pub fn truncate_graphemes(text: &str, max: u32, ellipsis: &str) -> String {
+    let seg = grapheme_segmenter();
+    // Boundary number `max` is the end of the max-th cluster
+    match seg.segment_str(text).nth(max as usize) {
+        Some(end) if end < text.len() => format!("{}{}", &text[..end], ellipsis),
+        _ => text.to_string(),
+    }
+}
+
+/// Cursor step to the left: the last grapheme boundary strictly before
+/// `byte_index`. An index inside a cluster snaps to that cluster's start, and
+/// 0 stays at 0.
+#[wasm_bindgen]
+pub fn grapheme_before(text: &str, byte_index: u32) -> u32 {
+    let index = (byte_index as usize).min(text.len());
+    let seg = grapheme_segmenter();
+    seg.segment_str(text)
+        .take_while(|&b| b < index)
+        .last()
+        .unwrap_or(0) as u32
+}
+
+/// Cursor step to the right: the first grapheme boundary strictly after
+/// `byte_index`. An index inside a cluster snaps to that cluster's end, and
+/// positions at or past the end return `text.len()`.
+#[wasm_bindgen]
+pub fn grapheme_after(text: &str, byte_index: u32) -> u32 {
+    let index = byte_index as usize;
+    let seg = grapheme_segmenter();
+    seg.segment_str(text)
+        .find(|&b| b > index)
+        .unwrap_or(text.len()) as u32
+}
+
+/// Byte length of the longest common prefix of `a` and `b` that ends on a
+/// grapheme boundary in both strings, so a cluster whose combining marks
+/// differ is never counted as shared.
+#[wasm_bindgen]
+pub fn common_prefix_len(a: &str, b: &str) -> u32 {
+    let shared = a.bytes().zip(b.bytes()).take_while(|(x, y)| x == y).count();
+    let seg = grapheme_segmenter();
+    let b_bounds: Vec<usize> = seg.segment_str(b).collect();
+    let a_bounds: Vec<usize> = seg.segment_str(a).collect();
+    a_bounds
+        .into_iter()
+        .rev()
+        .find(|&k| k <= shared && b_bounds.binary_search(&k).is_ok())
+        .unwrap_or(0) as u32
+}
+
+/// Byte length of the longest common suffix of `a` and `b` that starts on a
+/// grapheme boundary in both strings. The prefix and suffix may overlap when
+/// one string is a repetition of the other; callers diffing both ends should
+/// clamp the suffix to the shorter length minus the prefix.
+#[wasm_bindgen]
+pub fn common_suffix_len(a: &str, b: &str) -> u32 {
+    let shared = a
+        .bytes()
+        .rev()
+        .zip(b.bytes().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let seg = grapheme_segmenter();
+    let b_bounds: Vec<usize> = seg.segment_str(b).collect();
+    // Walk a's boundaries left to right, which is longest suffix first
+    seg.segment_str(a)
+        .map(|k| a.len() - k)
+        .find(|&len| len <= shared && b_bounds.binary_search(&(b.len() - len)).is_ok())
+        .unwrap_or(0) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WOMAN_TECHNOLOGIST_MEDIUM: &str = "\u{1F469}\u{1F3FD}\u{200D}\u{1F4BB}";
+    const FAMILY: &str = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    const FLAG_FI: &str = "\u{1F1EB}\u{1F1EE}";
+    const KEYCAP_ONE: &str = "1\u{FE0F}\u{20E3}";
+
+    #[test]
+    fn emoji_sequences_are_single_graphemes() {
+        for emoji in [WOMAN_TECHNOLOGIST_MEDIUM, FAMILY, FLAG_FI, KEYCAP_ONE] {
+            assert_eq!(count_graphemes(emoji), 1, "{emoji:?}");
+            assert_eq!(
+                grapheme_break_points(emoji),
+                [0, emoji.len() as u32],
+                "{emoji:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn adjacent_flags_pair_up() {
+        let flags = format!("{FLAG_FI}\u{1F1F8}\u{1F1EA}");
+        assert_eq!(count_graphemes(&flags), 2);
+        assert_eq!(grapheme_break_points(&flags), [0, 8, 16]);
+    }
+
+    #[test]
+    fn mixed_emoji_text() {
+        let text = format!("a{WOMAN_TECHNOLOGIST_MEDIUM}{KEYCAP_ONE}e\u{301}{FAMILY}");
+        assert_eq!(count_graphemes(&text), 5);
+        let a = 1;
+        let technologist = a + WOMAN_TECHNOLOGIST_MEDIUM.len();
+        let keycap = technologist + KEYCAP_ONE.len();
+        let accented = keycap + 3;
+        assert_eq!(
+            grapheme_break_points(&text),
+            [0, a, technologist, keycap, accented, text.len()].map(|b| b as u32)
+        );
+    }
+
+    #[test]
+    fn truncation_keeps_emoji_whole() {
+        let text = format!("{FAMILY}{WOMAN_TECHNOLOGIST_MEDIUM}{FLAG_FI}");
+        assert_eq!(
+            truncate_graphemes(&text, 2, "…"),
+            format!("{FAMILY}{WOMAN_TECHNOLOGIST_MEDIUM}…")
+        );
+        assert_eq!(truncate_graphemes(&text, 3, "…"), text);
+    }
+
+    #[test]
+    fn common_affixes_treat_changed_mark_as_changed_cluster() {
+        // Same base letter, different combining accent: nothing is shared
+        assert_eq!(common_prefix_len("e\u{301}x", "e\u{300}x"), 0);
+        assert_eq!(common_suffix_len("xe\u{301}", "xa\u{301}"), 0);
+        assert_eq!(common_prefix_len("caf\u{e9} au lait", "caf\u{e9} noir"), 6);
+        assert_eq!(
+            common_suffix_len("old text \u{1f44d}", "new text \u{1f44d}"),
+            10
+        );
+        // Adding a mark changes the cluster it attaches to
+        assert_eq!(common_prefix_len("abc", "abc\u{301}"), 2);
+    }
+
+    #[test]
+    fn cursor_steps_snap_and_clamp_around_emoji() {
+        // "a" 0..1, family 1..19, "b" 19..20
+        let text = format!("a{FAMILY}b");
+        assert_eq!(grapheme_before(&text, 10), 1);
+        assert_eq!(grapheme_before(&text, 19), 1);
+        assert_eq!(grapheme_before(&text, 1), 0);
+        assert_eq!(grapheme_before(&text, 0), 0);
+        assert_eq!(grapheme_before(&text, 100), 19);
+        assert_eq!(grapheme_after(&text, 10), 19);
+        assert_eq!(grapheme_after(&text, 0), 1);
+        assert_eq!(grapheme_after(&text, 1), 19);
+        assert_eq!(grapheme_after(&text, 19), 20);
+        assert_eq!(grapheme_after(&text, 20), 20);
+        assert_eq!(grapheme_after(&text, 100), 20);
+    }
+}
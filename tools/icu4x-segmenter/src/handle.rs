@@ -0,0 +1,103 @@
+//! A reusable handle over every segmenter compiled into this build.
+
+#[cfg(feature = "grapheme")]
+use icu_segmenter::GraphemeClusterSegmenter;
+#[cfg(feature = "line")]
+use icu_segmenter::LineSegmenter;
+#[cfg(feature = "sentence")]
+use icu_segmenter::SentenceSegmenter;
+#[cfg(feature = "word")]
+use icu_segmenter::WordSegmenter;
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "grapheme")]
+use crate::data::grapheme_segmenter;
+#[cfg(feature = "line")]
+use crate::data::line_segmenter;
+#[cfg(feature = "sentence")]
+use crate::data::sentence_segmenter;
+#[cfg(feature = "word")]
+use crate::data::word_segmenter;
+
+/// Reusable handle that builds each segmenter once, for callers segmenting many
+/// text nodes in a row.
+///
+/// Only the segmenters enabled at build time are held, and each
+/// `*_breaks` method exists only alongside its segmenter's feature.
+///
+/// Not `Send`/`Sync`: ICU4X data payloads are reference counted with `Rc`.
+/// In the browser this is moot because a WASM instance is confined to the
+/// thread (page, worker or offscreen document) that instantiated it, but a
+/// handle must not be shared with another worker; create one per instance.
+#[wasm_bindgen]
+pub struct Segmenter {
+    #[cfg(feature = "line")]
+    line: LineSegmenter,
+    #[cfg(feature = "word")]
+    word: WordSegmenter,
+    #[cfg(feature = "sentence")]
+    sentence: SentenceSegmenter,
+    #[cfg(feature = "grapheme")]
+    grapheme: GraphemeClusterSegmenter,
+}
+
+#[wasm_bindgen]
+impl Segmenter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Segmenter {
+        Segmenter {
+            #[cfg(feature = "line")]
+            line: line_segmenter(),
+            #[cfg(feature = "word")]
+            word: word_segmenter(),
+            #[cfg(feature = "sentence")]
+            sentence: sentence_segmenter(),
+            #[cfg(feature = "grapheme")]
+            grapheme: grapheme_segmenter(),
+        }
+    }
+}
+
+#[cfg(feature = "line")]
+#[wasm_bindgen]
+impl Segmenter {
+    pub fn line_breaks(&self, text: &str) -> Vec<u32> {
+        self.line.segment_str(text).map(|idx| idx as u32).collect()
+    }
+}
+
+#[cfg(feature = "word")]
+#[wasm_bindgen]
+impl Segmenter {
+    pub fn word_breaks(&self, text: &str) -> Vec<u32> {
+        self.word.segment_str(text).map(|idx| idx as u32).collect()
+    }
+}
+
+#[cfg(feature = "sentence")]
+#[wasm_bindgen]
+impl Segmenter {
+    pub fn sentence_breaks(&self, text: &str) -> Vec<u32> {
+        self.sentence
+            .segment_str(text)
+            .map(|idx| idx as u32)
+            .collect()
+    }
+}
+
+#[cfg(feature = "grapheme")]
+#[wasm_bindgen]
+impl Segmenter {
+    pub fn grapheme_breaks(&self, text: &str) -> Vec<u32> {
+        self.grapheme
+            .segment_str(text)
+            .map(|idx| idx as u32)
+            .collect()
+    }
+}
+
+impl Default for Segmenter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
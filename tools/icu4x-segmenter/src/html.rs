@@ -0,0 +1,219 @@
+//! Line breaking of HTML fragments, with offsets mapped back into the markup.
+
+use wasm_bindgen::prelude::*;
+
+use crate::data::line_segmenter;
+
+fn tag_end(html: &str, from: usize) -> usize {
+    // Byte offset just past the `>` closing the tag that starts at `from`,
+    // skipping `>` inside quoted attribute values and whole comments
+    let bytes = html.as_bytes();
+    if html[from..].starts_with("<!--") {
+        return html[from + 4..]
+            .find("-->")
+            .map_or(html.len(), |i| from + 4 + i + 3);
+    }
+    let mut quote = None;
+    for (i, &b) in bytes.iter().enumerate().skip(from + 1) {
+        match (quote, b) {
+            (None, b'"' | b'\'') => quote = Some(b),
+            (Some(q), _) if b == q => quote = None,
+            (None, b'>') => return i + 1,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+fn tag_name(tag: &str) -> String {
+    // Lowercased element name of an opening or closing tag ("" for comments and doctypes)
+    tag.trim_start_matches('<')
+        .trim_start_matches('/')
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
+
+fn raw_text_tag(tag: &str) -> Option<&'static str> {
+    // Opening tags whose content is not rendered text
+    if tag.starts_with("</") {
+        return None;
+    }
+    match tag_name(tag).as_str() {
+        "script" => Some("</script"),
+        "style" => Some("</style"),
+        _ => None,
+    }
+}
+
+fn breaks_line(tag: &str) -> bool {
+    // <br> and the edges of block-level elements start a new rendered line
+    matches!(
+        tag_name(tag).as_str(),
+        "br" | "p"
+            | "div"
+            | "li"
+            | "ul"
+            | "ol"
+            | "dl"
+            | "dt"
+            | "dd"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "blockquote"
+            | "pre"
+            | "hr"
+            | "table"
+            | "tr"
+            | "section"
+            | "article"
+            | "header"
+            | "footer"
+            | "nav"
+            | "aside"
+            | "main"
+            | "figure"
+            | "figcaption"
+    )
+}
+
+fn find_ignore_ascii_case(haystack: &str, from: usize, needle: &str) -> Option<usize> {
+    // Offset of the first case-insensitive match of an ASCII `needle` at or after `from`
+    let bytes = haystack.as_bytes();
+    let needle = needle.as_bytes();
+    (from..=bytes.len().checked_sub(needle.len())?)
+        .find(|&i| bytes[i..i + needle.len()].eq_ignore_ascii_case(needle))
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    // `entity` is the text between `&` and `;`
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{A0}'),
+        _ => {
+            let number = entity.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Line break opportunities of the rendered text of an HTML fragment, as byte
+/// offsets into `html` itself.
+///
+/// Tags and comments are skipped, `<script>`/`<style>` content is ignored,
+/// and the basic named entities plus numeric references are decoded before
+/// segmenting. `<br>` and the start or end of a block-level element (`p`,
+/// `div`, `li`, headings, table rows, ...) render as a newline, so they yield
+/// a mandatory break at the end of the tag; consecutive block edges collapse
+/// into one. The first and last offsets are 0 and `html.len()`; every other
+/// offset is the start of the source of the character after the break, so it
+/// never falls inside a tag, an attribute or an entity.
+#[wasm_bindgen]
+pub fn line_break_points_html(html: &str) -> Vec<u32> {
+    let mut rendered = String::with_capacity(html.len());
+    // sources[k]: offset in `html` of the markup that produced rendered byte k
+    let mut sources = Vec::with_capacity(html.len());
+    let mut i = 0;
+    while i < html.len() {
+        let rest = &html[i..];
+        // As in HTML, "<" only opens a tag when followed by a name, "/", "!" or "?"
+        let opens_tag = rest.strip_prefix('<').is_some_and(|tail| {
+            tail.starts_with(|c: char| c.is_ascii_alphabetic() || matches!(c, '/' | '!' | '?'))
+        });
+        if opens_tag {
+            let end = tag_end(html, i);
+            let tag = &html[i..end];
+            if breaks_line(tag) && !rendered.is_empty() && !rendered.ends_with('\n') {
+                rendered.push('\n');
+                sources.push(i);
+            }
+            i = match raw_text_tag(tag) {
+                Some(close) => find_ignore_ascii_case(html, end, close)
+                    .map_or(html.len(), |at| tag_end(html, at)),
+                None => end,
+            };
+            continue;
+        }
+        let (c, len) = match rest.strip_prefix('&').and_then(|tail| {
+            // Entities are short; never scan past 32 bytes for the `;`
+            let window = &tail.as_bytes()[..tail.len().min(33)];
+            let semi = window.iter().position(|&b| b == b';')?;
+            decode_entity(&tail[..semi]).map(|c| (c, semi + 2))
+        }) {
+            Some(decoded) => decoded,
+            None => {
+                let c = rest.chars().next().unwrap_or_default();
+                (c, c.len_utf8())
+            }
+        };
+        rendered.push(c);
+        sources.extend(std::iter::repeat_n(i, c.len_utf8()));
+        i += len;
+    }
+    let seg = line_segmenter();
+    let mut out = Vec::new();
+    for idx in seg.segment_str(&rendered) {
+        let offset = if idx == 0 {
+            0
+        } else if idx == rendered.len() {
+            html.len()
+        } else {
+            sources[idx]
+        };
+        out.push(offset as u32);
+    }
+    if rendered.is_empty() && !html.is_empty() {
+        out.push(html.len() as u32);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_breaks_map_into_markup() {
+        // <br> and block edges become hard breaks after the tag
+        assert_eq!(line_break_points_html("foo<br>bar"), [0, 7, 10]);
+        assert_eq!(line_break_points_html("<p>one</p><p>two</p>"), [0, 13, 20]);
+        // Inline tags are transparent
+        assert_eq!(line_break_points_html("<b>bold</b> text"), [0, 12, 16]);
+        assert_eq!(line_break_points_html("<p></p>"), [0, 7]);
+        assert_eq!(line_break_points_html(""), [0]);
+    }
+
+    #[test]
+    fn html_markup_is_skipped() {
+        // A quoted ">" does not end the tag
+        let quoted = "<a title=\"x>y\">hello world</a>";
+        assert_eq!(line_break_points_html(quoted), [0, 21, 30]);
+        assert_eq!(line_break_points_html("a <!-- b > c --> d"), [0, 17, 18]);
+        // Script content is ignored up to a case-insensitive close tag
+        let script = "x <script>if (a < b) {}</SCRIPT>y z";
+        assert_eq!(line_break_points_html(script), [0, 32, 34, 35]);
+        assert_eq!(line_break_points_html("x<style>p{}</style"), [0, 18]);
+    }
+
+    #[test]
+    fn html_entities_decode_before_segmenting() {
+        // NBSP forbids the break; the break after the space lands on "c"
+        assert_eq!(line_break_points_html("a&nbsp;b c&amp;d"), [0, 9, 16]);
+        // A bare ampersand is literal text
+        assert_eq!(line_break_points_html("AT&T rocks"), [0, 5, 10]);
+        assert_eq!(line_break_points_html("&#x3C;p&#62; x"), [0, 13, 14]);
+    }
+}
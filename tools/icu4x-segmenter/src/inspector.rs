@@ -0,0 +1,57 @@
+//! One-call segmentation report for the dev-mode inspector view.
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::bidi::base_direction;
+use crate::data::{grapheme_segmenter, sentence_segmenter};
+use crate::line::locale_line_segmenter;
+use crate::script::{detect_scripts, ScriptRun};
+use crate::word::word_segments;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SegmentReport {
+    line_breaks: Vec<u32>,
+    word_breaks: Vec<u32>,
+    /// Aligned with `word_breaks`: whether the segment ending at that offset is word-like
+    word_like: Vec<bool>,
+    sentence_breaks: Vec<u32>,
+    grapheme_breaks: Vec<u32>,
+    scripts: Vec<ScriptRun>,
+    direction: String,
+}
+
+/// Everything the inspector shows for a selection, as a plain JS object:
+/// `{ lineBreaks, wordBreaks, wordLike, sentenceBreaks, graphemeBreaks,
+/// scripts: [{ start, end, script }], direction }`. Offsets are UTF-8 bytes;
+/// `locale` tailors line breaking as in `line_break_points_for_locale`.
+#[wasm_bindgen]
+pub fn segment_full(text: &str, locale: &str) -> Result<JsValue, JsError> {
+    let line_breaks = locale_line_segmenter(locale)
+        .segment_str(text)
+        .map(|idx| idx as u32)
+        .collect();
+    let mut word_breaks = vec![0];
+    let mut word_like = vec![false];
+    for (_, end, is_word) in word_segments(text) {
+        word_breaks.push(end as u32);
+        word_like.push(is_word);
+    }
+    let report = SegmentReport {
+        line_breaks,
+        word_breaks,
+        word_like,
+        sentence_breaks: sentence_segmenter()
+            .segment_str(text)
+            .map(|idx| idx as u32)
+            .collect(),
+        grapheme_breaks: grapheme_segmenter()
+            .segment_str(text)
+            .map(|idx| idx as u32)
+            .collect(),
+        scripts: detect_scripts(text),
+        direction: base_direction(text),
+    };
+    Ok(serde_wasm_bindgen::to_value(&report)?)
+}
@@ -1,13 +1,170 @@
+//! WASM bindings over ICU4X segmentation and related text utilities.
+//!
+//! Every segmenter (`line`, `word`, `sentence`, `grapheme`) and every auxiliary
+//! ICU component (`properties`, `casemap`, `normalizer`, `collator`, `format`,
+//! `locale`) is a separate Cargo feature, and only `line` is on by default.
+//! Functions that combine components exist only when all of them are enabled;
+//! `feature_report` tells which ones a given binary was built with.
+
+#[cfg(feature = "properties")]
+mod bidi;
+#[cfg(feature = "casemap")]
+mod casemap;
+#[cfg(all(feature = "word", feature = "sentence", feature = "grapheme"))]
+mod chunk;
+#[cfg(feature = "collator")]
+mod collation;
+mod data;
+#[cfg(feature = "format")]
+mod format;
+#[cfg(feature = "grapheme")]
+mod grapheme;
+mod handle;
+#[cfg(feature = "line")]
+mod html;
+#[cfg(feature = "inspector")]
+mod inspector;
+#[cfg(feature = "line")]
+mod line;
+#[cfg(feature = "locale")]
+mod locale;
+#[cfg(feature = "normalizer")]
+mod normalize;
+#[cfg(feature = "properties")]
+mod script;
+#[cfg(feature = "sentence")]
+mod sentence;
+#[cfg(feature = "properties")]
+mod text;
+#[cfg(feature = "properties")]
+mod width;
+#[cfg(feature = "word")]
+mod word;
+
 use wasm_bindgen::prelude::*;
-use icu_segmenter::LineSegmenter;
 
+#[cfg(feature = "properties")]
+pub use bidi::*;
+#[cfg(feature = "casemap")]
+pub use casemap::*;
+#[cfg(all(feature = "word", feature = "sentence", feature = "grapheme"))]
+pub use chunk::*;
+#[cfg(feature = "collator")]
+pub use collation::*;
+#[cfg(feature = "buffer_provider")]
+pub use data::init_data;
+#[cfg(feature = "format")]
+pub use format::*;
+#[cfg(feature = "grapheme")]
+pub use grapheme::*;
+pub use handle::*;
+#[cfg(feature = "line")]
+pub use html::*;
+#[cfg(feature = "inspector")]
+pub use inspector::*;
+#[cfg(feature = "line")]
+pub use line::*;
+#[cfg(feature = "locale")]
+pub use locale::*;
+#[cfg(feature = "normalizer")]
+pub use normalize::*;
+#[cfg(feature = "properties")]
+pub use script::*;
+#[cfg(feature = "sentence")]
+pub use sentence::*;
+#[cfg(feature = "properties")]
+pub use text::*;
+#[cfg(feature = "properties")]
+pub use width::*;
+#[cfg(feature = "word")]
+pub use word::*;
+
+#[cfg(any(feature = "line", feature = "word", feature = "sentence"))]
+fn split_at_offsets(text: &str, offsets: impl Iterator<Item = usize>) -> Vec<String> {
+    // Segmenters always yield 0 and text.len(), so consecutive pairs cover the input without gaps
+    let mut out = Vec::new();
+    let mut start = 0;
+    for idx in offsets {
+        if idx > start {
+            out.push(text[start..idx].to_string());
+        }
+        start = idx;
+    }
+    out
+}
+
+#[cfg(any(
+    all(feature = "casemap", feature = "word"),
+    feature = "collator",
+    feature = "format",
+    feature = "hyphenation"
+))]
+fn parse_locale(locale: &str) -> icu_locid::Locale {
+    // Empty or invalid BCP-47 tags resolve to the root locale
+    locale.parse().unwrap_or(icu_locid::Locale::UND)
+}
+
+#[cfg(any(feature = "line", feature = "word"))]
+fn placeholder_ranges(text: &str, placeholders: &[String]) -> Vec<(usize, usize)> {
+    // Every occurrence of every non-empty placeholder; overlaps are harmless
+    let mut ranges = Vec::new();
+    for placeholder in placeholders.iter().filter(|p| !p.is_empty()) {
+        for (start, matched) in text.match_indices(placeholder.as_str()) {
+            ranges.push((start, start + matched.len()));
+        }
+    }
+    ranges
+}
+
+#[cfg(any(feature = "line", feature = "word"))]
+fn drop_breaks_inside(
+    text: &str,
+    breaks: impl Iterator<Item = usize>,
+    placeholders: &[String],
+) -> Vec<u32> {
+    // Placeholder edges become breaks too, even where the segmenter saw none
+    let ranges = placeholder_ranges(text, placeholders);
+    let mut out = Vec::new();
+    for idx in breaks {
+        if !ranges.iter().any(|&(start, end)| start < idx && idx < end) {
+            out.push(idx as u32);
+        }
+    }
+    for &(start, end) in &ranges {
+        out.extend([start as u32, end as u32]);
+    }
+    out.sort_unstable();
+    out.dedup();
+    out
+}
+
+/// Names of the Cargo features this binary was built with, in a fixed order:
+/// segmenters, then auxiliary components, then data sources and extras.
+/// Lets the extension verify that a shipped blob contains only what was
+/// enabled.
 #[wasm_bindgen]
-pub fn line_break_points(text: &str) -> Vec<u32> {
-    // Uses compiled_data feature; auto configuration selects reasonable defaults
-    let seg = LineSegmenter::new_auto();
+pub fn feature_report() -> Vec<String> {
+    let features = [
+        ("line", cfg!(feature = "line")),
+        ("word", cfg!(feature = "word")),
+        ("sentence", cfg!(feature = "sentence")),
+        ("grapheme", cfg!(feature = "grapheme")),
+        ("properties", cfg!(feature = "properties")),
+        ("casemap", cfg!(feature = "casemap")),
+        ("normalizer", cfg!(feature = "normalizer")),
+        ("collator", cfg!(feature = "collator")),
+        ("format", cfg!(feature = "format")),
+        ("locale", cfg!(feature = "locale")),
+        ("compiled_data", cfg!(feature = "compiled_data")),
+        ("buffer_provider", cfg!(feature = "buffer_provider")),
+        ("hyphenation", cfg!(feature = "hyphenation")),
+        ("inspector", cfg!(feature = "inspector")),
+    ];
     let mut out = Vec::new();
-    for idx in seg.segment_str(text) {
-        out.push(idx as u32);
+    for (name, enabled) in features {
+        if enabled {
+            out.push(name.to_string());
+        }
     }
     out
 }
@@ -0,0 +1,440 @@
+//! Line break opportunities (UAX #14): offsets in bytes, UTF-16 units or
+//! scalar values, CSS `line-break`/`word-break` tailoring, and a pull cursor.
+
+use std::collections::VecDeque;
+
+use icu_locid::Locale;
+use icu_segmenter::{LineBreakOptions, LineBreakStrictness, LineBreakWordOption, LineSegmenter};
+use wasm_bindgen::prelude::*;
+
+use crate::data::{line_segmenter, line_segmenter_with_options};
+use crate::{drop_breaks_inside, split_at_offsets};
+
+#[wasm_bindgen]
+pub fn line_break_points(text: &str) -> Vec<u32> {
+    // Auto configuration selects reasonable defaults; data comes from data.rs
+    let seg = line_segmenter();
+    let mut out = Vec::new();
+    for idx in seg.segment_str(text) {
+        out.push(idx as u32);
+    }
+    out
+}
+
+/// Same offsets as `line_break_points`, returned as a boxed slice, which
+/// wasm-bindgen always hands to JavaScript as a `Uint32Array`.
+///
+/// The generated glue copies the slice out of WASM memory in one bulk
+/// `TypedArray.slice()`; no per-element JS numbers are allocated. (The glue
+/// currently does the same for `Vec<u32>`, but this signature makes the typed
+/// array part of the contract rather than a binding detail.)
+#[wasm_bindgen]
+pub fn line_break_points_typed(text: &str) -> Box<[u32]> {
+    let seg = line_segmenter();
+    let out: Vec<u32> = seg.segment_str(text).map(|idx| idx as u32).collect();
+    out.into_boxed_slice()
+}
+
+#[wasm_bindgen]
+extern "C" {
+    // `performance` exists on both window and worker globals
+    #[wasm_bindgen(js_namespace = performance, js_name = now)]
+    fn performance_now() -> f64;
+}
+
+/// Line break offsets plus the time spent computing them inside WASM.
+#[wasm_bindgen]
+pub struct SegmentTiming {
+    offsets: Vec<u32>,
+    elapsed_us: f64,
+}
+
+#[wasm_bindgen]
+impl SegmentTiming {
+    #[wasm_bindgen(getter)]
+    pub fn offsets(&self) -> Vec<u32> {
+        self.offsets.clone()
+    }
+
+    /// Segmenter construction plus segmentation, in microseconds
+    #[wasm_bindgen(getter, js_name = elapsedMicros)]
+    pub fn elapsed_us(&self) -> f64 {
+        self.elapsed_us
+    }
+}
+
+/// Same result as `line_break_points`, timed with `performance.now()` for the
+/// performance dashboard. Kept separate so the hot path never calls into JS.
+#[wasm_bindgen]
+pub fn segment_with_timing(text: &str) -> SegmentTiming {
+    let start = performance_now();
+    let offsets = line_break_points(text);
+    let elapsed_us = (performance_now() - start) * 1000.0;
+    SegmentTiming {
+        offsets,
+        elapsed_us,
+    }
+}
+
+fn to_utf16_offsets(text: &str, byte_offsets: impl Iterator<Item = usize>) -> Vec<u32> {
+    // Offsets arrive in ascending order, so a single forward walk over the text converts them all
+    let mut out = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    let mut units = 0u32;
+    for offset in byte_offsets {
+        while let Some(&(pos, c)) = chars.peek() {
+            if pos >= offset {
+                break;
+            }
+            units += c.len_utf16() as u32;
+            chars.next();
+        }
+        out.push(units);
+    }
+    out
+}
+
+fn to_char_offsets(text: &str, byte_offsets: impl Iterator<Item = usize>) -> Vec<u32> {
+    // Same forward walk as to_utf16_offsets, counting one per scalar value
+    let mut out = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    let mut count = 0u32;
+    for offset in byte_offsets {
+        while chars.next_if(|&(pos, _)| pos < offset).is_some() {
+            count += 1;
+        }
+        out.push(count);
+    }
+    out
+}
+
+#[wasm_bindgen]
+pub fn line_break_points_utf16(text: &str) -> Vec<u32> {
+    // Same boundaries as line_break_points, as JavaScript string indices
+    let seg = line_segmenter();
+    to_utf16_offsets(text, seg.segment_str(text))
+}
+
+#[wasm_bindgen]
+pub fn line_break_points_chars(text: &str) -> Vec<u32> {
+    // Same boundaries as line_break_points, counted in Unicode scalar values
+    let seg = line_segmenter();
+    to_char_offsets(text, seg.segment_str(text))
+}
+
+pub(crate) fn locale_line_segmenter(locale: &str) -> LineSegmenter {
+    // ja/zh get the Chinese/Japanese hint but keep the default Strict rules,
+    // which already forbid breaks before small kana and other kinsoku characters
+    let ja_zh = locale
+        .parse::<Locale>()
+        .is_ok_and(|loc| matches!(loc.id.language.as_str(), "ja" | "zh"));
+    let mut options = LineBreakOptions::default();
+    options.ja_zh = ja_zh;
+    line_segmenter_with_options(options)
+}
+
+/// Line breaking tailored to a BCP-47 locale.
+///
+/// Japanese (`ja`) and Chinese (`zh`) set ICU4X's Chinese/Japanese hint on top of
+/// the default CSS `line-break: strict` rules. In ICU4X 1.5 that hint only
+/// relaxes the `normal` and `loose` levels, and nothing is stricter than the
+/// default, so no locale currently changes the output: every tag, including
+/// one that fails to parse, gives the same breaks as `line_break_points`.
+#[wasm_bindgen]
+pub fn line_break_points_for_locale(text: &str, locale: &str) -> Vec<u32> {
+    let seg = locale_line_segmenter(locale);
+    let mut out = Vec::new();
+    for idx in seg.segment_str(text) {
+        out.push(idx as u32);
+    }
+    out
+}
+
+/// Break offsets for several texts, flattened: the first `lengths[0]` entries of
+/// `offsets` belong to the first input, the next `lengths[1]` to the second, etc.
+#[wasm_bindgen]
+pub struct BatchBreaks {
+    offsets: Vec<u32>,
+    lengths: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl BatchBreaks {
+    #[wasm_bindgen(getter)]
+    pub fn offsets(&self) -> Vec<u32> {
+        self.offsets.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn lengths(&self) -> Vec<u32> {
+        self.lengths.clone()
+    }
+}
+
+#[wasm_bindgen]
+pub fn line_break_points_batch(texts: Vec<String>) -> BatchBreaks {
+    // One segmenter for the whole batch; output order follows input order
+    let seg = line_segmenter();
+    let mut offsets = Vec::new();
+    let mut lengths = Vec::with_capacity(texts.len());
+    for text in &texts {
+        let before = offsets.len();
+        for idx in seg.segment_str(text) {
+            offsets.push(idx as u32);
+        }
+        lengths.push((offsets.len() - before) as u32);
+    }
+    BatchBreaks { offsets, lengths }
+}
+
+fn parse_strictness(strictness: &str) -> LineBreakStrictness {
+    // Mirrors the CSS `line-break` keywords; `auto` and unknown values map to normal
+    match strictness.trim().to_ascii_lowercase().as_str() {
+        "loose" => LineBreakStrictness::Loose,
+        "strict" => LineBreakStrictness::Strict,
+        "anywhere" => LineBreakStrictness::Anywhere,
+        _ => LineBreakStrictness::Normal,
+    }
+}
+
+#[wasm_bindgen]
+pub fn line_break_points_with_strictness(text: &str, strictness: &str) -> Vec<u32> {
+    let mut options = LineBreakOptions::default();
+    options.strictness = parse_strictness(strictness);
+    let seg = line_segmenter_with_options(options);
+    let mut out = Vec::new();
+    for idx in seg.segment_str(text) {
+        out.push(idx as u32);
+    }
+    out
+}
+
+fn parse_word_option(word_break: &str) -> LineBreakWordOption {
+    // Mirrors the CSS `word-break` keywords; unknown values map to normal
+    match word_break.trim().to_ascii_lowercase().as_str() {
+        "break-all" => LineBreakWordOption::BreakAll,
+        "keep-all" => LineBreakWordOption::KeepAll,
+        _ => LineBreakWordOption::Normal,
+    }
+}
+
+/// Line breaking with both CSS `line-break` (`strictness`) and `word-break`
+/// (`word_break`: "normal", "break-all", "keep-all") applied.
+///
+/// The word option is evaluated first. `keep-all` suppresses breaks between
+/// letters, digits and Hangul/CJK ideographs regardless of strictness, even
+/// `anywhere`; strictness then only decides the remaining cases around
+/// punctuation, small kana and iteration marks. `break-all` lets letters break
+/// like ideographs, after which strictness applies as usual.
+#[wasm_bindgen]
+pub fn line_break_points_with_options(text: &str, strictness: &str, word_break: &str) -> Vec<u32> {
+    let mut options = LineBreakOptions::default();
+    options.strictness = parse_strictness(strictness);
+    options.word_option = parse_word_option(word_break);
+    let seg = line_segmenter_with_options(options);
+    let mut out = Vec::new();
+    for idx in seg.segment_str(text) {
+        out.push(idx as u32);
+    }
+    out
+}
+
+/// Line break opportunities with a parallel flag array: `mandatory[i]` is 1 when
+/// `offsets[i]` is a hard break (after LF, CR+LF, NEL, LS/PS or the end of text).
+#[wasm_bindgen]
+pub struct LineBreaks {
+    offsets: Vec<u32>,
+    mandatory: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl LineBreaks {
+    #[wasm_bindgen(getter)]
+    pub fn offsets(&self) -> Vec<u32> {
+        self.offsets.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn mandatory(&self) -> Vec<u8> {
+        self.mandatory.clone()
+    }
+}
+
+fn is_hard_break(c: char) -> bool {
+    // The BK, CR, LF and NL classes are a fixed set, so no property data is needed
+    matches!(
+        c,
+        '\n' | '\r' | '\u{B}' | '\u{C}' | '\u{85}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+pub(crate) fn follows_hard_break(text: &str, idx: usize) -> bool {
+    // True when the character before `idx` forces a line break
+    text[..idx].chars().next_back().is_some_and(is_hard_break)
+}
+
+#[wasm_bindgen]
+pub fn line_breaks_detailed(text: &str) -> LineBreaks {
+    // The segmenter never breaks inside CR+LF, so each hard line break yields exactly one offset
+    let seg = line_segmenter();
+    let mut offsets = Vec::new();
+    let mut mandatory = Vec::new();
+    for idx in seg.segment_str(text) {
+        let hard = idx > 0 && (idx == text.len() || follows_hard_break(text, idx));
+        offsets.push(idx as u32);
+        mandatory.push(hard as u8);
+    }
+    LineBreaks { offsets, mandatory }
+}
+
+/// Pull-based line breaking over an owned copy of the text, for callers that
+/// want to stop early (e.g. once the viewport is filled) instead of
+/// materializing every offset.
+///
+/// Yields the same offsets as `line_break_points`, starting with 0. Breaks
+/// inside a Thai run or after spaces depend on the surrounding text, so the
+/// cursor never restarts mid-paragraph: it segments one paragraph (up to and
+/// including a mandatory break) when first entering it and hands out that
+/// paragraph's buffered offsets. Text without hard breaks is therefore
+/// segmented in one go on the first call after 0.
+#[wasm_bindgen]
+pub struct LineBreakCursor {
+    text: String,
+    segmenter: LineSegmenter,
+    pos: usize,
+    pending: VecDeque<usize>,
+    started: bool,
+}
+
+fn paragraph_end(text: &str, from: usize) -> usize {
+    // Offset just past the first mandatory break at or after `from`, keeping CR+LF together
+    let mut chars = text[from..].char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '\r' && chars.peek().is_some_and(|&(_, next)| next == '\n') {
+            return from + i + 2;
+        }
+        if is_hard_break(c) {
+            return from + i + c.len_utf8();
+        }
+    }
+    text.len()
+}
+
+#[wasm_bindgen]
+impl LineBreakCursor {
+    #[wasm_bindgen(constructor)]
+    pub fn new(text: String) -> LineBreakCursor {
+        LineBreakCursor {
+            text,
+            segmenter: line_segmenter(),
+            pos: 0,
+            pending: VecDeque::new(),
+            started: false,
+        }
+    }
+
+    /// Next break offset in UTF-8 bytes, or `undefined` once the end is reached.
+    #[wasm_bindgen(js_name = next)]
+    pub fn next_break(&mut self) -> Option<u32> {
+        if !self.started {
+            self.started = true;
+            return Some(0);
+        }
+        if self.pending.is_empty() && self.pos < self.text.len() {
+            let end = paragraph_end(&self.text, self.pos);
+            // skip(1) drops the leading 0 the segmenter reports for the paragraph
+            let start = self.pos;
+            let breaks = self.segmenter.segment_str(&self.text[start..end]).skip(1);
+            self.pending.extend(breaks.map(|b| start + b));
+            self.pos = end;
+        }
+        self.pending.pop_front().map(|b| b as u32)
+    }
+}
+
+impl Iterator for LineBreakCursor {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.next_break()
+    }
+}
+
+#[wasm_bindgen]
+pub fn split_into_lines(text: &str) -> Vec<String> {
+    let seg = line_segmenter();
+    split_at_offsets(text, seg.segment_str(text))
+}
+
+/// Line break opportunities with none falling strictly inside an occurrence
+/// of any of `placeholders` (e.g. "{user_name}" or "%s"), so each placeholder
+/// stays on one line. Each placeholder's start and end are reported as break
+/// opportunities as well, even where ICU found none.
+#[wasm_bindgen]
+pub fn line_break_points_protected(text: &str, placeholders: Vec<String>) -> Vec<u32> {
+    drop_breaks_inside(text, line_segmenter().segment_str(text), &placeholders)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_breaks_keep_kinsoku() {
+        // No break before the small kana ぁ/ぃ or the wave dash, for any tag
+        let text = "あぁいぃ〜う";
+        assert_eq!(line_break_points(text), [0, 6, 15, 18]);
+        for locale in ["ja", "zh-Hant", "en", "not a tag!", ""] {
+            assert_eq!(line_break_points_for_locale(text, locale), [0, 6, 15, 18]);
+        }
+    }
+
+    #[test]
+    fn protected_breaks_skip_placeholder_interiors() {
+        let text = "Dear {first-name}, welcome";
+        // Unprotected, ICU allows a break after the hyphen
+        assert!(line_break_points(text).contains(&12));
+        let breaks = line_break_points_protected(text, vec!["{first-name}".to_string()]);
+        assert!(breaks.iter().all(|&b| !(5 < b && b < 17)));
+    }
+
+    #[test]
+    fn detailed_breaks_count_crlf_once() {
+        let breaks = line_breaks_detailed("a\r\nb c\n");
+        assert_eq!(breaks.offsets(), [0, 3, 5, 7]);
+        assert_eq!(breaks.mandatory(), [0, 1, 0, 1]);
+        let empty = line_breaks_detailed("");
+        assert_eq!(empty.offsets(), [0]);
+        assert_eq!(empty.mandatory(), [0]);
+    }
+
+    #[test]
+    fn utf16_offsets_count_astral_scalars_twice() {
+        assert_eq!(line_break_points("\u{1F600} a"), [0, 5, 6]);
+        assert_eq!(line_break_points_utf16("\u{1F600} a"), [0, 3, 4]);
+    }
+
+    #[test]
+    fn cursor_matches_eager_breaks() {
+        for text in [
+            "The quick (brown) fox \u{201C}jumps\u{201D} over\r\nthe lazy dog.",
+            "日本語の文章です。「かぎ括弧」も\n含みます",
+            "ภาษาไทยเป็นภาษาที่ยาก ภาษาไทย",
+            "สวัสดีครับ\nภาษาไทยเป็นภาษาที่ยาก\n\nend",
+            "",
+        ] {
+            let cursor: Vec<u32> = LineBreakCursor::new(text.to_string()).collect();
+            assert_eq!(cursor, line_break_points(text), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn char_offsets_match_bytes_only_for_ascii() {
+        let ascii = "plain ascii text, no surprises";
+        assert_eq!(line_break_points_chars(ascii), line_break_points(ascii));
+        // "é" is 2 bytes, "👍" 4 bytes and "東" 3 bytes, but one scalar each
+        let mixed = "héllo \u{1F44D} wörld 東京へ";
+        assert_eq!(line_break_points(mixed), [0, 7, 12, 19, 22, 25, 28]);
+        assert_eq!(line_break_points_chars(mixed), [0, 6, 8, 14, 15, 16, 17]);
+    }
+}
@@ -0,0 +1,36 @@
+//! BCP-47 locale tag validation and canonicalization.
+
+use icu_locid::Locale;
+use icu_locid_transform::LocaleCanonicalizer;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn canonicalize_locale(tag: &str) -> Option<String> {
+    // Accepts "_" separators and any casing; deprecated codes ("iw", "in") map to current ones
+    let mut locale: Locale = tag.trim().replace('_', "-").parse().ok()?;
+    LocaleCanonicalizer::new().canonicalize(&mut locale);
+    Some(locale.to_string())
+}
+
+#[wasm_bindgen]
+pub fn locale_language(tag: &str) -> String {
+    // Unparseable tags yield "und", matching the root fallback used elsewhere
+    canonicalize_locale(tag)
+        .and_then(|canonical| canonical.split('-').next().map(str::to_owned))
+        .unwrap_or_else(|| "und".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonicalizes_casing_separators_and_deprecated_codes() {
+        assert_eq!(canonicalize_locale("EN_us").as_deref(), Some("en-US"));
+        assert_eq!(canonicalize_locale("iw").as_deref(), Some("he"));
+        assert_eq!(canonicalize_locale(""), None);
+        assert_eq!(canonicalize_locale("zz!!"), None);
+        assert_eq!(locale_language("pt_BR"), "pt");
+        assert_eq!(locale_language("bad tag"), "und");
+    }
+}
@@ -0,0 +1,42 @@
+//! Unicode normalization forms and diacritic stripping.
+
+use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn normalize(text: &str, form: &str) -> String {
+    // "nfc", "nfd", "nfkc" or "nfkd" (case-insensitive); anything else is treated as NFC
+    match form.trim().to_ascii_lowercase().as_str() {
+        "nfd" => DecomposingNormalizer::new_nfd().normalize(text),
+        "nfkc" => ComposingNormalizer::new_nfkc().normalize(text),
+        "nfkd" => DecomposingNormalizer::new_nfkd().normalize(text),
+        _ => ComposingNormalizer::new_nfc().normalize(text),
+    }
+}
+
+/// Strips accents for fuzzy matching: "naïve" -> "naive", "Crème" -> "Creme".
+///
+/// Decomposes to NFD, drops marks from the generic combining diacritics blocks
+/// (U+0300..U+036F, U+1AB0..U+1AFF, U+1DC0..U+1DFF, U+20D0..U+20FF,
+/// U+FE20..U+FE2F) and recomposes to NFC. Script-specific marks such as kana
+/// voicing marks, Indic vowel signs or Hebrew points are kept, so "が" stays
+/// "が", and precomposed letters without a decomposition ("ø", "ł") are left
+/// untouched.
+#[wasm_bindgen]
+pub fn remove_diacritics(text: &str) -> String {
+    let stripped: String = DecomposingNormalizer::new_nfd()
+        .normalize(text)
+        .chars()
+        .filter(|&c| {
+            !matches!(
+                c,
+                '\u{0300}'..='\u{036F}'
+                    | '\u{1AB0}'..='\u{1AFF}'
+                    | '\u{1DC0}'..='\u{1DFF}'
+                    | '\u{20D0}'..='\u{20FF}'
+                    | '\u{FE20}'..='\u{FE2F}'
+            )
+        })
+        .collect();
+    ComposingNormalizer::new_nfc().normalize(&stripped)
+}
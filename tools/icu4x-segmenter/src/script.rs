@@ -0,0 +1,298 @@
+//! Script detection and the script-based heuristics built on it.
+
+use icu_properties::{maps, Script};
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "word")]
+use crate::word::word_segments;
+
+/// A maximal run of text in one script, as UTF-8 byte offsets `[start, end)`
+/// and an ISO 15924 code such as "Latn" or "Hani".
+#[wasm_bindgen]
+#[cfg_attr(feature = "inspector", derive(serde::Serialize))]
+pub struct ScriptRun {
+    pub start: u32,
+    pub end: u32,
+    script: String,
+}
+
+#[wasm_bindgen]
+impl ScriptRun {
+    #[wasm_bindgen(getter)]
+    pub fn script(&self) -> String {
+        self.script.clone()
+    }
+}
+
+fn is_neutral_script(script: Script) -> bool {
+    matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+}
+
+pub(crate) fn script_code(script: Script) -> String {
+    Script::enum_to_short_name_mapper()
+        .get(script)
+        .map_or_else(|| "Zzzz".to_string(), |code| code.to_string())
+}
+
+#[wasm_bindgen]
+pub fn detect_scripts(text: &str) -> Vec<ScriptRun> {
+    // Common/Inherited characters extend the preceding run (or the following one at
+    // the start of the text); text made only of them is a single "Zyyy" run
+    let scripts = maps::script();
+    let mut out = Vec::new();
+    let mut start = 0;
+    let mut current: Option<Script> = None;
+    for (i, c) in text.char_indices() {
+        let script = scripts.get(c);
+        if is_neutral_script(script) {
+            continue;
+        }
+        match current {
+            Some(prev) if prev != script => {
+                out.push(ScriptRun {
+                    start: start as u32,
+                    end: i as u32,
+                    script: script_code(prev),
+                });
+                start = i;
+                current = Some(script);
+            }
+            Some(_) => {}
+            None => current = Some(script),
+        }
+    }
+    if !text.is_empty() {
+        out.push(ScriptRun {
+            start: start as u32,
+            end: text.len() as u32,
+            script: script_code(current.unwrap_or(Script::Common)),
+        });
+    }
+    out
+}
+
+#[wasm_bindgen]
+pub fn contains_cjk(text: &str) -> bool {
+    let scripts = maps::script();
+    text.chars().any(|c| {
+        matches!(
+            scripts.get(c),
+            Script::Han | Script::Hiragana | Script::Katakana | Script::Hangul
+        )
+    })
+}
+
+fn script_counts(text: &str) -> Vec<(Script, u32)> {
+    // Characters per non-Common/Inherited script, in order of first appearance
+    let scripts = maps::script();
+    let mut counts: Vec<(Script, u32)> = Vec::new();
+    for c in text.chars() {
+        let script = scripts.get(c);
+        if is_neutral_script(script) {
+            continue;
+        }
+        match counts.iter_mut().find(|(s, _)| *s == script) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((script, 1)),
+        }
+    }
+    counts
+}
+
+#[wasm_bindgen]
+pub fn dominant_script(text: &str) -> String {
+    // Most frequent script by character count, ties going to the one seen first;
+    // "Zyyy" when the text has only Common/Inherited characters
+    let mut best: Option<(Script, u32)> = None;
+    for (script, n) in script_counts(text) {
+        if best.is_none_or(|(_, m)| n > m) {
+            best = Some((script, n));
+        }
+    }
+    script_code(best.map_or(Script::Common, |(script, _)| script))
+}
+
+fn scripts_in(text: &str) -> Vec<Script> {
+    script_counts(text)
+        .into_iter()
+        .map(|(script, _)| script)
+        .collect()
+}
+
+#[wasm_bindgen]
+pub fn script_set(text: &str) -> Vec<String> {
+    scripts_in(text).into_iter().map(script_code).collect()
+}
+
+#[cfg(feature = "word")]
+/// Whether any single word mixes scripts in a way real text does not, e.g.
+/// "pаypal" with a Cyrillic "а". Follows the UTS #39 "highly restrictive"
+/// profile: a word may use one script, or Latin together with the Han-based
+/// combinations Japanese (Han, Hiragana, Katakana), Chinese (Han, Bopomofo)
+/// and Korean (Han, Hangul) writing actually uses.
+#[wasm_bindgen]
+pub fn is_mixed_script(text: &str) -> bool {
+    const ALLOWED: [&[Script]; 3] = [
+        &[
+            Script::Latin,
+            Script::Han,
+            Script::Hiragana,
+            Script::Katakana,
+        ],
+        &[Script::Latin, Script::Han, Script::Bopomofo],
+        &[Script::Latin, Script::Han, Script::Hangul],
+    ];
+    word_segments(text)
+        .into_iter()
+        .filter(|&(_, _, word_like)| word_like)
+        .any(|(start, end, _)| {
+            let scripts = scripts_in(&text[start..end]);
+            scripts.len() > 1
+                && !ALLOWED
+                    .iter()
+                    .any(|allowed| scripts.iter().all(|s| allowed.contains(s)))
+        })
+}
+
+/// A ranked language guess from `detect_language`, with `confidence` in
+/// `0.0..=1.0`.
+#[wasm_bindgen]
+pub struct LanguageGuess {
+    locale: String,
+    pub confidence: f32,
+}
+
+#[wasm_bindgen]
+impl LanguageGuess {
+    #[wasm_bindgen(getter)]
+    pub fn locale(&self) -> String {
+        self.locale.clone()
+    }
+}
+
+/// Coarse, script-based language identification, most likely first.
+///
+/// Each script's share of the letters is scaled by how reliably the script
+/// names one language: Hangul, Thai or Greek map one-to-one, while Cyrillic
+/// and Arabic only get a single-letter hint for Ukrainian and Persian. Han
+/// counts as Japanese when kana appear, Korean alongside Hangul, else Chinese.
+/// Latin-script text names no language and contributes no guess, so empty,
+/// symbol-only or Latin-only input returns an empty list.
+#[wasm_bindgen]
+pub fn detect_language(text: &str) -> Vec<LanguageGuess> {
+    let counts = script_counts(text);
+    let total: u32 = counts.iter().map(|(_, n)| n).sum();
+    let has = |script| counts.iter().any(|(s, _)| *s == script);
+    let han = if has(Script::Hiragana) || has(Script::Katakana) {
+        ("ja", 1.0)
+    } else if has(Script::Hangul) {
+        ("ko", 1.0)
+    } else {
+        // Kanji-only Japanese is indistinguishable from Chinese
+        ("zh", 0.9)
+    };
+    let has_char = |letters: &[char]| text.chars().any(|c| letters.contains(&c));
+    let mut guesses: Vec<(&str, f32)> = Vec::new();
+    for &(script, n) in &counts {
+        let (locale, weight) = match script {
+            Script::Han => han,
+            Script::Hiragana | Script::Katakana => ("ja", 1.0),
+            Script::Hangul => ("ko", 1.0),
+            Script::Thai => ("th", 1.0),
+            Script::Lao => ("lo", 1.0),
+            Script::Khmer => ("km", 1.0),
+            Script::Myanmar => ("my", 0.9),
+            Script::Greek => ("el", 1.0),
+            Script::Hebrew => ("he", 0.9),
+            Script::Georgian => ("ka", 1.0),
+            Script::Armenian => ("hy", 1.0),
+            Script::Cyrillic if has_char(&['і', 'ї', 'є', 'ґ', 'І', 'Ї', 'Є', 'Ґ']) => {
+                ("uk", 0.7)
+            }
+            Script::Cyrillic => ("ru", 0.6),
+            Script::Arabic if has_char(&['پ', 'چ', 'ژ', 'گ']) => ("fa", 0.7),
+            Script::Arabic => ("ar", 0.6),
+            Script::Devanagari => ("hi", 0.8),
+            Script::Bengali => ("bn", 0.9),
+            Script::Gurmukhi => ("pa", 1.0),
+            Script::Gujarati => ("gu", 1.0),
+            Script::Tamil => ("ta", 1.0),
+            Script::Telugu => ("te", 1.0),
+            Script::Kannada => ("kn", 1.0),
+            Script::Malayalam => ("ml", 1.0),
+            Script::Sinhala => ("si", 1.0),
+            Script::Ethiopian => ("am", 0.8),
+            Script::Tibetan => ("bo", 0.9),
+            _ => continue,
+        };
+        let share = weight * n as f32 / total as f32;
+        match guesses.iter_mut().find(|(l, _)| *l == locale) {
+            Some((_, confidence)) => *confidence += share,
+            None => guesses.push((locale, share)),
+        }
+    }
+    // Stable sort keeps first-seen order among equal confidences
+    guesses.sort_by(|a, b| b.1.total_cmp(&a.1));
+    guesses
+        .into_iter()
+        .map(|(locale, confidence)| LanguageGuess {
+            locale: locale.to_string(),
+            confidence,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runs(text: &str) -> Vec<(u32, u32, String)> {
+        detect_scripts(text)
+            .into_iter()
+            .map(|run| (run.start, run.end, run.script()))
+            .collect()
+    }
+
+    #[test]
+    fn script_runs_absorb_neutral_characters() {
+        // The space joins the preceding Latin run
+        assert_eq!(
+            runs("Hello 世界"),
+            [(0, 6, "Latn".to_string()), (6, 12, "Hani".to_string())]
+        );
+        // Leading digits and spaces join the first real run
+        assert_eq!(runs("123 abc"), [(0, 7, "Latn".to_string())]);
+        assert_eq!(runs("123 !?"), [(0, 6, "Zyyy".to_string())]);
+        assert!(runs("").is_empty());
+    }
+
+    #[test]
+    fn script_set_lists_scripts_in_first_seen_order() {
+        assert_eq!(script_set("東京 and мир, Tokyo"), ["Hani", "Latn", "Cyrl"]);
+        assert!(script_set("123 !?").is_empty());
+    }
+
+    #[cfg(feature = "word")]
+    #[test]
+    fn mixed_script_flags_confusable_words() {
+        // Cyrillic "а" and Greek "α" inside a Latin word
+        assert!(is_mixed_script("pаypal"));
+        assert!(is_mixed_script("pαypal"));
+        assert!(!is_mixed_script("paypal"));
+        // Latin with Han is an allowed combination; separate words may differ
+        assert!(!is_mixed_script("Tokyo東京"));
+        assert!(!is_mixed_script("hello мир"));
+    }
+
+    #[test]
+    fn detect_language_reads_han_by_its_companions() {
+        let top = |text: &str| detect_language(text).first().map(|g| g.locale());
+        assert_eq!(top("東京に行きます").as_deref(), Some("ja"));
+        assert_eq!(top("韓國 한국어").as_deref(), Some("ko"));
+        assert_eq!(top("中文文本").as_deref(), Some("zh"));
+        assert_eq!(top("Привіт, світе").as_deref(), Some("uk"));
+        assert_eq!(top("Привет, мир").as_deref(), Some("ru"));
+        assert!(detect_language("hello").is_empty());
+        assert!(detect_language("").is_empty());
+    }
+}
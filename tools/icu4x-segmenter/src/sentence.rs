@@ -0,0 +1,41 @@
+//! Sentence boundaries (UAX #29).
+
+use wasm_bindgen::prelude::*;
+
+use crate::data::sentence_segmenter;
+use crate::split_at_offsets;
+
+#[wasm_bindgen]
+pub fn sentence_break_points(text: &str) -> Vec<u32> {
+    // UAX #29 sentence rules; the final offset is always text.len(), even without terminal punctuation
+    let seg = sentence_segmenter();
+    let mut out = Vec::new();
+    for idx in seg.segment_str(text) {
+        out.push(idx as u32);
+    }
+    out
+}
+
+/// Sentence boundaries for text in `locale`.
+///
+/// ICU4X 1.5 ships only the root UAX #29 sentence rules: there is no
+/// per-locale tailoring and no CLDR abbreviation suppressions, so every locale
+/// (valid or not) currently yields the same offsets as `sentence_break_points`.
+/// Call sites should still pass the page locale so they pick up tailoring once
+/// the segmenter data supports it.
+#[wasm_bindgen]
+pub fn sentence_break_points_for_locale(text: &str, locale: &str) -> Vec<u32> {
+    let _ = locale;
+    let seg = sentence_segmenter();
+    let mut out = Vec::new();
+    for idx in seg.segment_str(text) {
+        out.push(idx as u32);
+    }
+    out
+}
+
+#[wasm_bindgen]
+pub fn split_into_sentences(text: &str) -> Vec<String> {
+    let seg = sentence_segmenter();
+    split_at_offsets(text, seg.segment_str(text))
+}
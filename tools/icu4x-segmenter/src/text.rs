@@ -0,0 +1,80 @@
+//! Unicode-aware whitespace handling and per-character property lookup.
+
+use icu_properties::{maps, sets, BidiClass, GeneralCategory};
+use wasm_bindgen::prelude::*;
+
+use crate::script::script_code;
+
+#[wasm_bindgen]
+pub fn trim(text: &str) -> String {
+    // Unicode White_Space, so NBSP, U+3000 IDEOGRAPHIC SPACE and U+2028/2029 are trimmed too
+    let white_space = sets::white_space();
+    text.trim_matches(|c| white_space.contains(c)).to_string()
+}
+
+/// Trims `text` and replaces every internal run of Unicode White_Space
+/// (including NBSP, U+3000 and line breaks) with a single ASCII space.
+/// Whitespace-only input becomes the empty string.
+#[wasm_bindgen]
+pub fn collapse_whitespace(text: &str) -> String {
+    let white_space = sets::white_space();
+    let mut out = String::with_capacity(text.len());
+    let mut pending_space = false;
+    for c in text.chars() {
+        if white_space.contains(c) {
+            pending_space = !out.is_empty();
+        } else {
+            if pending_space {
+                out.push(' ');
+                pending_space = false;
+            }
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Unicode properties of one scalar value, keyed by its UTF-8 byte offset.
+/// Category, script and bidi class use the short property value aliases
+/// ("Lu", "Latn", "AL").
+#[wasm_bindgen(getter_with_clone)]
+pub struct CharProperties {
+    pub offset: u32,
+    #[wasm_bindgen(js_name = codePoint)]
+    pub code_point: u32,
+    #[wasm_bindgen(js_name = generalCategory)]
+    pub general_category: String,
+    pub script: String,
+    #[wasm_bindgen(js_name = bidiClass)]
+    pub bidi_class: String,
+    #[wasm_bindgen(js_name = whiteSpace)]
+    pub white_space: bool,
+    pub alphabetic: bool,
+}
+
+/// Per-scalar property dump for the inspector. Meant for a character or a
+/// short selection: the output has one object per scalar value.
+#[wasm_bindgen]
+pub fn char_properties(text: &str) -> Vec<CharProperties> {
+    let categories = maps::general_category();
+    let category_names = GeneralCategory::enum_to_short_name_mapper();
+    let scripts = maps::script();
+    let bidi = maps::bidi_class();
+    let bidi_names = BidiClass::enum_to_short_name_mapper();
+    let white_space = sets::white_space();
+    let alphabetic = sets::alphabetic();
+    text.char_indices()
+        .map(|(i, c)| CharProperties {
+            offset: i as u32,
+            code_point: c as u32,
+            general_category: category_names
+                .get(categories.get(c))
+                .unwrap_or("Cn")
+                .to_string(),
+            script: script_code(scripts.get(c)),
+            bidi_class: bidi_names.get(bidi.get(c)).unwrap_or("L").to_string(),
+            white_space: white_space.contains(c),
+            alphabetic: alphabetic.contains(c),
+        })
+        .collect()
+}
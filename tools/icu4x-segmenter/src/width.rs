@@ -0,0 +1,108 @@
+//! Monospace display width and column wrapping.
+
+use icu_properties::{maps, EastAsianWidth, GeneralCategory};
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "line")]
+use crate::data::line_segmenter;
+#[cfg(feature = "line")]
+use crate::line::follows_hard_break;
+
+fn char_width(c: char) -> u32 {
+    // Combining marks, format characters (ZWJ, bidi controls) and controls take no column
+    match maps::general_category().get(c) {
+        GeneralCategory::NonspacingMark
+        | GeneralCategory::EnclosingMark
+        | GeneralCategory::Format
+        | GeneralCategory::Control => return 0,
+        _ => {}
+    }
+    match maps::east_asian_width().get(c) {
+        EastAsianWidth::Wide | EastAsianWidth::Fullwidth => 2,
+        _ => 1,
+    }
+}
+
+/// Monospace column count: East Asian Wide and Fullwidth characters take two
+/// columns, combining marks and other zero-width characters none, and
+/// everything else (including Ambiguous) one.
+#[wasm_bindgen]
+pub fn display_width(text: &str) -> u32 {
+    text.chars().map(char_width).sum()
+}
+
+#[cfg(feature = "line")]
+/// Line break offsets (including 0 and `text.len()`) that wrap `text` into lines
+/// of at most `cols` display columns, cutting only at line break opportunities.
+///
+/// Trailing whitespace does not count against a line, mandatory breaks are
+/// always kept, and a segment wider than `cols` on its own still gets emitted,
+/// alone on an overlong line.
+#[wasm_bindgen]
+pub fn wrap_to_columns(text: &str, cols: u32) -> Vec<u32> {
+    let seg = line_segmenter();
+    let mut out = vec![0];
+    let mut line_start = 0;
+    let mut line_width = 0;
+    let mut prev = 0;
+    for idx in seg.segment_str(text).skip(1) {
+        let segment = &text[prev..idx];
+        if line_start < prev && line_width + display_width(segment.trim_end()) > cols {
+            out.push(prev as u32);
+            line_start = prev;
+            line_width = 0;
+        }
+        line_width += display_width(segment);
+        if idx < text.len() && follows_hard_break(text, idx) {
+            out.push(idx as u32);
+            line_start = idx;
+            line_width = 0;
+        }
+        prev = idx;
+    }
+    if !text.is_empty() {
+        out.push(text.len() as u32);
+    }
+    out
+}
+
+#[cfg(feature = "line")]
+/// `text` re-wrapped to `width` display columns by inserting "\n" at the
+/// offsets `wrap_to_columns` picks. Existing hard line breaks are kept
+/// verbatim, trailing whitespace before an inserted break is dropped, and a
+/// word wider than `width` is left whole on its own line.
+#[wasm_bindgen]
+pub fn wrap_text(text: &str, width: u32) -> String {
+    let breaks = wrap_to_columns(text, width);
+    let mut out = String::with_capacity(text.len() + breaks.len());
+    for pair in breaks.windows(2) {
+        let (start, end) = (pair[0] as usize, pair[1] as usize);
+        let line = &text[start..end];
+        if end == text.len() || follows_hard_break(text, end) {
+            out.push_str(line);
+        } else {
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(all(test, feature = "line"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_text_respects_width_and_hard_breaks() {
+        assert_eq!(
+            wrap_text("the quick brown fox jumps\nover the lazy dog", 10),
+            "the quick\nbrown fox\njumps\nover the\nlazy dog"
+        );
+        assert_eq!(
+            wrap_text("a supercalifragilistic b", 5),
+            "a\nsupercalifragilistic\nb"
+        );
+        assert_eq!(wrap_text("日本語の文章です", 6), "日本語\nの文章\nです");
+        assert_eq!(wrap_text("", 6), "");
+    }
+}
@@ -0,0 +1,271 @@
+//! Word boundaries (UAX #29) and the word-level helpers built on them.
+
+#[cfg(all(feature = "casemap", feature = "normalizer"))]
+use icu_casemap::CaseMapper;
+#[cfg(all(feature = "casemap", feature = "normalizer"))]
+use icu_normalizer::ComposingNormalizer;
+#[cfg(feature = "properties")]
+use icu_properties::sets;
+use wasm_bindgen::prelude::*;
+
+use crate::data::{dictionary_word_segmenter, word_segmenter};
+use crate::{drop_breaks_inside, split_at_offsets};
+
+#[wasm_bindgen]
+pub fn word_break_points(text: &str) -> Vec<u32> {
+    // Auto configuration uses dictionary data for CJK and LSTM for Southeast Asian scripts
+    let seg = word_segmenter();
+    let mut out = Vec::new();
+    for idx in seg.segment_str(text) {
+        out.push(idx as u32);
+    }
+    out
+}
+
+/// Word boundaries using dictionaries for every complex script: Chinese,
+/// Japanese, Thai, Lao, Khmer and Burmese.
+///
+/// `word_break_points` already segments these scripts (LSTM models for the
+/// Southeast Asian ones); this variant trades those models for dictionary
+/// lookups, which are faster and deterministic on in-vocabulary text such as
+/// "ภาษาไทย" -> "ภาษา" + "ไทย". Both data sets are linked in through the
+/// `compiled_data` and `auto` features, so neither function can silently fall
+/// back to treating a run as a single word because data is missing.
+#[wasm_bindgen]
+pub fn word_break_points_dict(text: &str) -> Vec<u32> {
+    let seg = dictionary_word_segmenter();
+    let mut out = Vec::new();
+    for idx in seg.segment_str(text) {
+        out.push(idx as u32);
+    }
+    out
+}
+
+/// `[start, end]` of the word segment containing `byte_index`, for
+/// double-click selection. A position on a boundary selects the segment that
+/// starts there, positions at or past the end select the last segment, and a
+/// position inside whitespace selects the whole whitespace run.
+#[wasm_bindgen]
+pub fn word_range_at(text: &str, byte_index: u32) -> Vec<u32> {
+    let index = (byte_index as usize).min(text.len());
+    let seg = word_segmenter();
+    let mut start = 0;
+    for idx in seg.segment_str(text).skip(1) {
+        if idx > index || idx == text.len() {
+            return vec![start as u32, idx as u32];
+        }
+        start = idx;
+    }
+    vec![0, 0]
+}
+
+pub(crate) fn word_segments(text: &str) -> Vec<(usize, usize, bool)> {
+    // `(start, end, word_like)` per word segment. The segmenter tags the last
+    // dictionary/LSTM piece of a CJK or Thai run with the type of whatever
+    // follows it ("行きます 3" marks "ます" as non-word), so a segment holding
+    // any letter or digit also counts as word-like
+    let seg = word_segmenter();
+    let mut iter = seg.segment_str(text);
+    let mut out = Vec::new();
+    let mut start = 0;
+    for (end, word_type) in iter.iter_with_word_type().skip(1) {
+        let word_like =
+            word_type.is_word_like() || text[start..end].chars().any(char::is_alphanumeric);
+        out.push((start, end, word_like));
+        start = end;
+    }
+    out
+}
+
+#[wasm_bindgen]
+pub fn count_words(text: &str) -> u32 {
+    // Counts only word-like segments (letters, numbers, ideographs); CJK runs are split by dictionary
+    let words = word_segments(text);
+    words.iter().filter(|&&(_, _, word_like)| word_like).count() as u32
+}
+
+#[wasm_bindgen]
+pub fn split_into_words(text: &str) -> Vec<String> {
+    let seg = word_segmenter();
+    split_at_offsets(text, seg.segment_str(text))
+}
+
+/// Word boundaries with those inside any of `placeholders` removed and each
+/// placeholder's start and end added, so every placeholder comes out as a
+/// single segment even when glued to neighbouring text ("50%salary").
+#[wasm_bindgen]
+pub fn word_break_points_protected(text: &str, placeholders: Vec<String>) -> Vec<u32> {
+    drop_breaks_inside(text, word_segmenter().segment_str(text), &placeholders)
+}
+
+#[cfg(feature = "properties")]
+/// A word segment as UTF-8 byte offsets `[start, end)` with its `kind`:
+/// "word", "whitespace" or "punctuation".
+#[wasm_bindgen]
+pub struct WordSegment {
+    pub start: u32,
+    pub end: u32,
+    kind: String,
+}
+
+#[cfg(feature = "properties")]
+#[wasm_bindgen]
+impl WordSegment {
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> String {
+        self.kind.clone()
+    }
+}
+
+#[cfg(feature = "properties")]
+/// Every word segment of `text`, in order and covering it without gaps, so
+/// translating only the "word" runs and concatenating the rest verbatim
+/// preserves the original spacing. Leading and trailing whitespace come out
+/// as their own segments. Non-word segments that are not entirely
+/// White_Space (punctuation, symbols, emoji) are all "punctuation".
+#[wasm_bindgen]
+pub fn segment_words_classified(text: &str) -> Vec<WordSegment> {
+    let white_space = sets::white_space();
+    let mut out = Vec::new();
+    for (start, end, word_like) in word_segments(text) {
+        let kind = if word_like {
+            "word"
+        } else if text[start..end].chars().all(|c| white_space.contains(c)) {
+            "whitespace"
+        } else {
+            "punctuation"
+        };
+        out.push(WordSegment {
+            start: start as u32,
+            end: end as u32,
+            kind: kind.to_string(),
+        });
+    }
+    out
+}
+
+#[cfg(all(feature = "casemap", feature = "normalizer"))]
+/// Word-like segments of `text`, case folded and NFC-normalized, for building
+/// translation-memory keys and n-grams.
+///
+/// Punctuation and whitespace segments are dropped. CJK runs are split with
+/// the segmenter's dictionaries, contractions such as "don't" stay one token,
+/// and hyphenated compounds such as "well-known" yield "well" and "known".
+#[wasm_bindgen]
+pub fn tokenize_words(text: &str) -> Vec<String> {
+    let case_mapper = CaseMapper::new();
+    let nfc = ComposingNormalizer::new_nfc();
+    let mut out = Vec::new();
+    for (start, end, word_like) in word_segments(text) {
+        if word_like {
+            out.push(nfc.normalize(&case_mapper.fold_string(&text[start..end])));
+        }
+    }
+    out
+}
+
+#[cfg(feature = "hyphenation")]
+thread_local! {
+    // Deserializing the embedded patterns is costly, so it happens once per instance
+    static EN_US_PATTERNS: Option<hyphenation::Standard> = {
+        use hyphenation::{Language, Load, Standard};
+        Standard::from_embedded(Language::EnglishUS).ok()
+    };
+}
+
+/// Byte offsets inside words where a soft hyphen could be inserted, e.g.
+/// [2, 6, 7] ("hy-phen-a-tion") for English "hyphenation".
+///
+/// ICU4X has no hyphenation data, so this uses the TeX (Liang) patterns from
+/// the `hyphenation` crate behind the `hyphenation` feature. Only English is
+/// embedded (`en` and all its regions use the en-US patterns); any other
+/// locale, or a build without the feature, returns an empty vector.
+#[wasm_bindgen]
+pub fn hyphenation_points(text: &str, locale: &str) -> Vec<u32> {
+    #[cfg(feature = "hyphenation")]
+    {
+        use hyphenation::Hyphenator;
+
+        use crate::parse_locale;
+
+        if parse_locale(locale).id.language.as_str() != "en" {
+            return Vec::new();
+        }
+        EN_US_PATTERNS.with(|patterns| {
+            let Some(dictionary) = patterns else {
+                return Vec::new();
+            };
+            let mut out = Vec::new();
+            for (start, end, word_like) in word_segments(text) {
+                if word_like {
+                    for b in dictionary.hyphenate(&text[start..end]).breaks {
+                        out.push((start + b) as u32);
+                    }
+                }
+            }
+            out
+        })
+    }
+    #[cfg(not(feature = "hyphenation"))]
+    {
+        let _ = (text, locale);
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "casemap", feature = "normalizer"))]
+    fn tokenize_words_keeps_final_cjk_word() {
+        assert_eq!(
+            tokenize_words("Don't STOP the well-known Cafe\u{301}! 東京に行きます 3.14"),
+            [
+                "don't", "stop", "the", "well", "known", "café", "東京", "に", "行き", "ます",
+                "3.14"
+            ]
+        );
+        assert_eq!(count_words("東京に行きます 3.14"), 5);
+    }
+
+    #[test]
+    fn thai_splits_into_dictionary_words() {
+        // "ภาษา" + "ไทย", whether run through the dictionary or the LSTM model
+        assert_eq!(word_break_points_dict("ภาษาไทย"), [0, 12, 21]);
+        assert_eq!(word_break_points("ภาษาไทย"), [0, 12, 21]);
+    }
+
+    #[test]
+    fn word_range_selects_word_whitespace_or_last_segment() {
+        assert_eq!(word_range_at("foo bar", 1), [0, 3]);
+        assert_eq!(word_range_at("foo   bar", 4), [3, 6]);
+        // On a boundary the segment starting there wins; past the end, the last
+        assert_eq!(word_range_at("foo bar", 4), [4, 7]);
+        assert_eq!(word_range_at("foo bar", 7), [4, 7]);
+        assert_eq!(word_range_at("foo bar", 99), [4, 7]);
+        assert_eq!(word_range_at("", 0), [0, 0]);
+    }
+
+    #[test]
+    fn protected_word_breaks_keep_placeholder_whole() {
+        let text = "Dear {first-name}, welcome";
+        let words = word_break_points_protected(text, vec!["{first-name}".to_string()]);
+        assert!(words.contains(&5) && words.contains(&17));
+        assert!(words.iter().all(|&b| !(5 < b && b < 17)));
+        assert_eq!(
+            word_break_points_protected("50%salary", vec!["%s".to_string()]),
+            [0, 2, 4, 9]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "hyphenation")]
+    fn hyphenation_points_follow_en_us_patterns() {
+        assert_eq!(hyphenation_points("hyphenation", "en"), [2, 6, 7]);
+        // Offsets are relative to the whole text, words are hyphenated separately
+        assert_eq!(hyphenation_points("a hyphenation", "en-GB"), [4, 8, 9]);
+        assert!(hyphenation_points("hyphenation", "de").is_empty());
+    }
+}